@@ -0,0 +1,84 @@
+//! Bounce a square around the display, drawing each frame while the previous one is still being
+//! sent to the display.
+//!
+//! This example is for the STM32F103 "Blue Pill" board using I2C1.
+//!
+//! Wiring connections are as follows for a CRIUS-branded display:
+//!
+//! ```
+//!      Display -> Blue Pill
+//! (black)  GND -> GND
+//! (red)    +5V -> VCC
+//! (yellow) SDA -> PB7
+//! (green)  SCL -> PB6
+//! ```
+//!
+//! Run on a Blue Pill with `cargo run --example async_double_buffer_i2c`.
+
+#![no_std]
+#![no_main]
+
+use defmt_rtt as _;
+use embassy_executor::Spawner;
+use embassy_futures::join::join;
+use embassy_stm32::{bind_interrupts, i2c, peripherals, time::Hertz};
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+};
+use panic_probe as _;
+use ssd1306::{prelude::*, I2CDisplayInterface, Ssd1306Async};
+
+bind_interrupts!(struct Irqs {
+    I2C1_EV => i2c::EventInterruptHandler<peripherals::I2C1>;
+    I2C1_ER => i2c::ErrorInterruptHandler<peripherals::I2C1>;
+});
+
+#[embassy_executor::main]
+async fn main(_spawner: Spawner) {
+    let p = embassy_stm32::init(Default::default());
+    let i2c = embassy_stm32::i2c::I2c::new(
+        p.I2C1,
+        p.PB6,
+        p.PB7,
+        Irqs,
+        p.DMA1_CH6,
+        p.DMA1_CH7,
+        Hertz::khz(400),
+        Default::default(),
+    );
+
+    let interface = I2CDisplayInterface::new(i2c);
+    let mut display = Ssd1306Async::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+        .into_double_buffered_graphics_mode();
+    display.init().await.unwrap();
+
+    let style = PrimitiveStyle::with_fill(BinaryColor::On);
+    let mut position = Point::new(0, 0);
+    let mut velocity = Point::new(3, 2);
+
+    loop {
+        // Send the last frame to the display while drawing the next one into the back buffer
+        let (mut back, transfer) = display.begin_present();
+
+        let (result, _) = join(transfer, async {
+            back.clear(BinaryColor::Off).unwrap();
+
+            Rectangle::new(position, Size::new(16, 16))
+                .into_styled(style)
+                .draw(&mut back)
+                .unwrap();
+        })
+        .await;
+        result.unwrap();
+
+        position += velocity;
+        if position.x <= 0 || position.x >= 128 - 16 {
+            velocity.x = -velocity.x;
+        }
+        if position.y <= 0 || position.y >= 64 - 16 {
+            velocity.y = -velocity.y;
+        }
+    }
+}
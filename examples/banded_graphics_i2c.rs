@@ -0,0 +1,91 @@
+//! Draw a square, circle and triangle on the screen using only 256 bytes of framebuffer memory.
+//!
+//! The scene is drawn once for each band of two pages (16 rows) and each band is sent to the
+//! display before the next one is drawn.
+//!
+//! This example is for the STM32F103 "Blue Pill" board using I2C1.
+//!
+//! Wiring connections are as follows for a CRIUS-branded display:
+//!
+//! ```
+//!      Display -> Blue Pill
+//! (black)  GND -> GND
+//! (red)    +5V -> VCC
+//! (yellow) SDA -> PB7
+//! (green)  SCL -> PB6
+//! ```
+//!
+//! Run on a Blue Pill with `cargo run --example banded_graphics_i2c`.
+
+#![no_std]
+#![no_main]
+
+use cortex_m::asm::nop;
+use cortex_m_rt::entry;
+use defmt_rtt as _;
+use display_interface::DisplayError;
+use embassy_stm32::time::Hertz;
+use embedded_graphics::{
+    pixelcolor::BinaryColor,
+    prelude::*,
+    primitives::{Circle, PrimitiveStyleBuilder, Rectangle, Triangle},
+};
+use panic_probe as _;
+use ssd1306::{mode::Band, prelude::*, I2CDisplayInterface, Ssd1306};
+
+fn draw_scene(band: &mut Band<'_>) -> Result<(), DisplayError> {
+    let yoffset = 20;
+
+    let style = PrimitiveStyleBuilder::new()
+        .stroke_width(1)
+        .stroke_color(BinaryColor::On)
+        .build();
+
+    // screen outline
+    Rectangle::new(Point::new(0, 0), Size::new(127, 63))
+        .into_styled(style)
+        .draw(band)?;
+
+    // triangle
+    Triangle::new(
+        Point::new(16, 16 + yoffset),
+        Point::new(16 + 16, 16 + yoffset),
+        Point::new(16 + 8, yoffset),
+    )
+    .into_styled(style)
+    .draw(band)?;
+
+    // square
+    Rectangle::new(Point::new(52, yoffset), Size::new_equal(16))
+        .into_styled(style)
+        .draw(band)?;
+
+    // circle
+    Circle::new(Point::new(88, yoffset), 16)
+        .into_styled(style)
+        .draw(band)
+}
+
+#[entry]
+fn main() -> ! {
+    let p = embassy_stm32::init(Default::default());
+
+    let i2c = embassy_stm32::i2c::I2c::new_blocking(
+        p.I2C1,
+        p.PB6,
+        p.PB7,
+        Hertz::khz(400),
+        Default::default(),
+    );
+
+    let interface = I2CDisplayInterface::new(i2c);
+    let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+        .into_banded_graphics_mode::<256>();
+    display.init().unwrap();
+
+    display.draw_bands(draw_scene).unwrap();
+
+    loop {
+        nop()
+    }
+}
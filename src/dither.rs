@@ -0,0 +1,432 @@
+//! Drawing grayscale and color images on the display.
+//!
+//! [`Dither`] wraps a monochrome [`DrawTarget`], such as a display in
+//! [`BufferedGraphicsMode`](crate::mode::BufferedGraphicsMode), and accepts any color that can be
+//! converted to [`Gray8`], e.g. `Gray8` or `Rgb565`. Each pixel is turned on or off according to
+//! the selected [`DitherMethod`]. [`ErrorDiffusion`] does the same using Floyd-Steinberg error
+//! diffusion, which needs to keep the error of two rows of pixels.
+//!
+//! ```rust
+//! # use ssd1306::test_helpers::StubInterface;
+//! # let interface = StubInterface;
+//! use embedded_graphics::{
+//!     pixelcolor::Rgb565,
+//!     prelude::*,
+//!     primitives::{Circle, PrimitiveStyle},
+//! };
+//! use ssd1306::{
+//!     dither::{Dither, DitherMethod},
+//!     prelude::*,
+//!     Ssd1306,
+//! };
+//!
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//! display.init().unwrap();
+//!
+//! Circle::new(Point::new(32, 0), 64)
+//!     .into_styled(PrimitiveStyle::with_fill(Rgb565::CSS_ORANGE))
+//!     .draw(&mut Dither::new(&mut display, DitherMethod::Ordered))
+//!     .unwrap();
+//!
+//! display.flush().unwrap();
+//! ```
+
+use core::marker::PhantomData;
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::Dimensions,
+    pixelcolor::{BinaryColor, Gray8, GrayColor, PixelColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+/// The largest width of a display, which limits the columns error diffusion is carried across.
+const MAX_WIDTH: usize = 128;
+
+/// 4x4 Bayer matrix, giving the order in which pixels of a 4x4 tile are turned on as the gray
+/// level increases.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// How gray levels are converted to pixels that are either on or off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DitherMethod {
+    /// Turn on pixels whose gray level is at least the given value
+    Threshold(u8),
+    /// Ordered dithering with a 4x4 Bayer matrix. The pattern depends only on the position of
+    /// each pixel, so areas drawn separately line up without seams.
+    Ordered,
+}
+
+impl DitherMethod {
+    /// Convert the gray level of the pixel at (x, y) to on or off.
+    fn quantize(self, x: i32, y: i32, luma: u8) -> bool {
+        match self {
+            Self::Threshold(threshold) => luma >= threshold,
+            Self::Ordered => {
+                let rank = BAYER_4X4[y.rem_euclid(4) as usize][x.rem_euclid(4) as usize];
+                // Thresholds are spread evenly over the gray levels, from 8 to 248
+                u16::from(luma) >= u16::from(rank) * 16 + 8
+            }
+        }
+    }
+}
+
+impl Default for DitherMethod {
+    fn default() -> Self {
+        Self::Threshold(128)
+    }
+}
+
+/// The error carried between pixels by [`ErrorDiffusion`].
+#[derive(Clone, Debug)]
+struct DiffusionState {
+    /// The row that `current` belongs to
+    row: Option<i32>,
+    /// Error added to the pixels of the current row
+    current: [i16; MAX_WIDTH],
+    /// Error added to the pixels of the next row
+    next: [i16; MAX_WIDTH],
+}
+
+impl DiffusionState {
+    const fn new() -> Self {
+        Self {
+            row: None,
+            current: [0; MAX_WIDTH],
+            next: [0; MAX_WIDTH],
+        }
+    }
+
+    /// Quantize the gray level at (x, y) and spread the error to the neighbouring pixels.
+    fn quantize(&mut self, x: i32, y: i32, luma: u8) -> bool {
+        match self.row {
+            Some(row) if row == y => {}
+            Some(row) if row + 1 == y => {
+                self.current = self.next;
+                self.next = [0; MAX_WIDTH];
+            }
+            _ => {
+                self.current = [0; MAX_WIDTH];
+                self.next = [0; MAX_WIDTH];
+            }
+        }
+        self.row = Some(y);
+
+        let Some(col) = usize::try_from(x).ok().filter(|col| *col < MAX_WIDTH) else {
+            return luma >= 128;
+        };
+
+        let value = i16::from(luma) + self.current[col];
+        let on = value >= 128;
+        let error = value - if on { 255 } else { 0 };
+
+        let spread = |errors: &mut [i16; MAX_WIDTH], col: Option<usize>, weight: i16| {
+            if let Some(error_at) = col.and_then(|col| errors.get_mut(col)) {
+                *error_at += error * weight / 16;
+            }
+        };
+        spread(&mut self.current, Some(col + 1), 7);
+        spread(&mut self.next, col.checked_sub(1), 3);
+        spread(&mut self.next, Some(col), 5);
+        spread(&mut self.next, Some(col + 1), 1);
+
+        on
+    }
+}
+
+/// A [`DrawTarget`] adapter that dithers colors to [`BinaryColor`].
+///
+/// Pixels are positioned in the coordinates of the wrapped target, so ordered dithering patterns
+/// of separately drawn areas line up. See the [module documentation](self) for an example.
+#[derive(Debug)]
+pub struct Dither<'a, T, C> {
+    target: &'a mut T,
+    method: DitherMethod,
+    color: PhantomData<C>,
+}
+
+impl<'a, T, C> Dither<'a, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    /// Wrap `target`, converting colors using `method`.
+    pub fn new(target: &'a mut T, method: DitherMethod) -> Self {
+        Self {
+            target,
+            method,
+            color: PhantomData,
+        }
+    }
+
+    /// Get the method used to convert colors.
+    pub fn method(&self) -> DitherMethod {
+        self.method
+    }
+
+    /// Set the method used to convert colors.
+    pub fn set_method(&mut self, method: DitherMethod) {
+        self.method = method;
+    }
+}
+
+impl<T, C> DrawTarget for Dither<'_, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let method = self.method;
+
+        self.target
+            .draw_iter(pixels.into_iter().map(|Pixel(pos, color)| {
+                let on = method.quantize(pos.x, pos.y, color.into().luma());
+                Pixel(pos, BinaryColor::from(on))
+            }))
+    }
+}
+
+impl<T, C> Dimensions for Dither<'_, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+/// A [`DrawTarget`] adapter that dithers colors to [`BinaryColor`] using Floyd-Steinberg error
+/// diffusion, which spreads the difference between the gray level and the drawn pixel to the
+/// pixels to the right and below it.
+///
+/// The error is carried over from one row to the next, also between separate draw calls, as long
+/// as the rows are drawn from top to bottom. Areas next to each other should be drawn as one, or
+/// with [`DitherMethod::Ordered`], to avoid visible seams.
+///
+/// The error of two rows of up to 128 pixels is kept in the adapter, which takes 512 bytes.
+///
+/// ```rust
+/// # use ssd1306::test_helpers::StubInterface;
+/// # let interface = StubInterface;
+/// use embedded_graphics::{pixelcolor::Gray8, prelude::*, primitives::Rectangle};
+/// use ssd1306::{dither::ErrorDiffusion, prelude::*, Ssd1306};
+///
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().unwrap();
+///
+/// let area = Rectangle::new(Point::zero(), Size::new(128, 64));
+/// ErrorDiffusion::new(&mut display)
+///     .fill_solid(&area, Gray8::new(64))
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ErrorDiffusion<'a, T, C> {
+    target: &'a mut T,
+    state: DiffusionState,
+    color: PhantomData<C>,
+}
+
+impl<'a, T, C> ErrorDiffusion<'a, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    /// Wrap `target`, converting colors using error diffusion.
+    pub fn new(target: &'a mut T) -> Self {
+        Self {
+            target,
+            state: DiffusionState::new(),
+            color: PhantomData,
+        }
+    }
+
+    /// Forget the error carried over from the pixels drawn so far.
+    pub fn reset(&mut self) {
+        self.state = DiffusionState::new();
+    }
+}
+
+impl<T, C> DrawTarget for ErrorDiffusion<'_, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+    C: PixelColor + Into<Gray8>,
+{
+    type Color = C;
+    type Error = T::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let state = &mut self.state;
+
+        self.target
+            .draw_iter(pixels.into_iter().map(|Pixel(pos, color)| {
+                let on = state.quantize(pos.x, pos.y, color.into().luma());
+                Pixel(pos, BinaryColor::from(on))
+            }))
+    }
+}
+
+impl<T, C> Dimensions for ErrorDiffusion<'_, T, C>
+where
+    T: DrawTarget<Color = BinaryColor>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics_core::{
+        geometry::{OriginDimensions, Point, Size},
+        primitives::Rectangle,
+    };
+
+    /// A 16x8 monochrome target.
+    #[derive(Debug, Default)]
+    struct Canvas([[bool; 16]; 8]);
+
+    impl DrawTarget for Canvas {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+
+        fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+        where
+            I: IntoIterator<Item = Pixel<Self::Color>>,
+        {
+            for Pixel(pos, color) in pixels {
+                self.0[pos.y as usize][pos.x as usize] = color.is_on();
+            }
+            Ok(())
+        }
+    }
+
+    impl OriginDimensions for Canvas {
+        fn size(&self) -> Size {
+            Size::new(16, 8)
+        }
+    }
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    /// Check the top left part of `canvas` against rows of `#` for pixels that are on and `.` for
+    /// pixels that are off.
+    fn assert_rows(canvas: &Canvas, expected: &[&str]) {
+        for (y, row) in expected.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                assert_eq!(canvas.0[y][x], c == '#', "pixel ({x}, {y}) of {row:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn threshold_turns_on_pixels_at_the_threshold() {
+        let mut canvas = Canvas::default();
+        let pixels = [(0, 99), (1, 100), (2, 101), (3, 0), (4, 255)]
+            .map(|(x, luma)| Pixel(Point::new(x, 0), Gray8::new(luma)));
+
+        Dither::new(&mut canvas, DitherMethod::Threshold(100))
+            .draw_iter(pixels)
+            .unwrap();
+
+        assert_rows(&canvas, &[".##.#"]);
+    }
+
+    #[test]
+    fn ordered_draws_bayer_pattern() {
+        for (luma, expected) in [
+            (0, ["....", "....", "....", "...."]),
+            (8, ["#...", "....", "....", "...."]),
+            (72, ["#.#.", ".#..", "#.#.", "...."]),
+            (128, ["#.#.", ".#.#", "#.#.", ".#.#"]),
+            (247, ["####", "####", "####", ".###"]),
+            (255, ["####", "####", "####", "####"]),
+        ] {
+            let mut canvas = Canvas::default();
+            Dither::new(&mut canvas, DitherMethod::Ordered)
+                .fill_solid(&rect(0, 0, 16, 8), Gray8::new(luma))
+                .unwrap();
+
+            // The pattern repeats every 4 pixels in both directions
+            for (tile_x, tile_y) in [(0, 0), (4, 0), (12, 4)] {
+                for (y, row) in expected.iter().enumerate() {
+                    for (x, c) in row.chars().enumerate() {
+                        assert_eq!(
+                            canvas.0[tile_y + y][tile_x + x],
+                            c == '#',
+                            "gray level {luma}, pixel ({x}, {y}) of tile ({tile_x}, {tile_y})",
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ordered_areas_line_up_without_seams() {
+        let gray = Gray8::new(100);
+
+        let mut whole = Canvas::default();
+        Dither::new(&mut whole, DitherMethod::Ordered)
+            .fill_solid(&rect(0, 0, 16, 8), gray)
+            .unwrap();
+
+        // Split at positions that are not a multiple of the pattern size, with separate adapters
+        let mut parts = Canvas::default();
+        for area in [rect(0, 0, 7, 3), rect(7, 0, 9, 3), rect(0, 3, 16, 5)] {
+            Dither::new(&mut parts, DitherMethod::Ordered)
+                .fill_solid(&area, gray)
+                .unwrap();
+        }
+
+        assert_eq!(whole.0, parts.0);
+    }
+
+    #[test]
+    fn error_diffusion_of_half_gray_is_a_checkerboard() {
+        let mut canvas = Canvas::default();
+        ErrorDiffusion::new(&mut canvas)
+            .fill_solid(&rect(0, 0, 16, 8), Gray8::new(128))
+            .unwrap();
+
+        for y in 0..8 {
+            for x in 0..16 {
+                assert_eq!(canvas.0[y][x], (x + y) % 2 == 0, "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn error_diffusion_carries_error_between_rows() {
+        let gray = Gray8::new(128);
+
+        // The first row leaves an error that turns the first pixel of the second row off
+        let mut canvas = Canvas::default();
+        let mut diffusion = ErrorDiffusion::new(&mut canvas);
+        diffusion.fill_solid(&rect(0, 0, 16, 1), gray).unwrap();
+        diffusion.fill_solid(&rect(0, 1, 16, 1), gray).unwrap();
+        assert_rows(&canvas, &["#.#.#.#.", ".#.#.#.#"]);
+
+        // Without it the second row is drawn like the first one
+        let mut canvas = Canvas::default();
+        let mut diffusion = ErrorDiffusion::new(&mut canvas);
+        diffusion.fill_solid(&rect(0, 0, 16, 1), gray).unwrap();
+        diffusion.reset();
+        diffusion.fill_solid(&rect(0, 1, 16, 1), gray).unwrap();
+        assert_rows(&canvas, &["#.#.#.#.", "#.#.#.#."]);
+    }
+}
@@ -111,6 +111,8 @@
 
 mod brightness;
 pub mod command;
+#[cfg(feature = "graphics")]
+pub mod dither;
 mod error;
 mod i2c_interface;
 pub mod mode;
@@ -135,9 +137,15 @@ use embedded_hal::{delay::DelayNs, digital::OutputPin};
 #[cfg(feature = "async")]
 use embedded_hal_async::delay::DelayNs as DelayNsAsync;
 use error::Error;
-use mode::{BufferedGraphicsMode, TerminalMode};
+use mode::{
+    BandedGraphicsMode, BufferedGraphicsMode, DirtyArea, GrayscaleMode, LayeredGraphicsMode,
+    TerminalMode,
+};
 #[cfg(feature = "async")]
-use mode::{BufferedGraphicsModeAsync, TerminalModeAsync};
+use mode::{
+    BufferedGraphicsModeAsync, DoubleBufferedGraphicsModeAsync, GrayscaleModeAsync,
+    LayeredGraphicsModeAsync, TerminalModeAsync,
+};
 use rotation::DisplayRotation;
 use size::DisplaySize;
 #[cfg(feature = "async")]
@@ -154,6 +162,8 @@ pub struct Ssd1306<DI, SIZE, MODE> {
     size: SIZE,
     addr_mode: AddrMode,
     rotation: DisplayRotation,
+    flip_x: bool,
+    flip_y: bool,
 }
 
 #[maybe_async_cfg::maybe(
@@ -174,6 +184,8 @@ where
             addr_mode: AddrMode::Page,
             mode: BasicMode,
             rotation,
+            flip_x: false,
+            flip_y: false,
         }
     }
 }
@@ -185,6 +197,8 @@ where
         idents(
             DisplaySize(async = "DisplaySizeAsync"),
             BufferedGraphicsMode(async = "BufferedGraphicsModeAsync"),
+            GrayscaleMode(async = "GrayscaleModeAsync"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync"),
             TerminalMode(async = "TerminalModeAsync"),
         )
     )
@@ -201,6 +215,8 @@ where
             interface: self.interface,
             size: self.size,
             rotation: self.rotation,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
         }
     }
 
@@ -212,12 +228,126 @@ where
         self.into_mode(BufferedGraphicsMode::new())
     }
 
+    /// Convert the display into a buffered graphics mode that draws into a caller-provided
+    /// buffer instead of one stored inline in the display.
+    ///
+    /// This avoids moving the buffer around with the display, for example when the buffer is
+    /// placed in a `static` or a dedicated RAM section. The buffer is used as-is, so either clear
+    /// it or call `init` before the first flush.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut buffer = [0u8; 1024];
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode_with_buffer(&mut buffer);
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(0, 9, true);
+    /// display.flush().unwrap();
+    /// # drop(display);
+    /// assert_eq!(buffer[128], 0b0000_0010);
+    /// ```
+    ///
+    /// See [`BufferedGraphicsMode`] for more information.
+    pub fn into_buffered_graphics_mode_with_buffer(
+        self,
+        buffer: &mut SIZE::Buffer,
+    ) -> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, &mut SIZE::Buffer>> {
+        self.into_mode(BufferedGraphicsMode::with_buffer(buffer))
+    }
+
+    /// Convert the display into a banded graphics mode, supporting
+    /// [embedded-graphics](https://crates.io/crates/embedded-graphics) while keeping only `N`
+    /// bytes of the framebuffer in memory.
+    ///
+    /// `N` must be at least the width of the display, so that the buffer holds a single page.
+    /// Smaller buffers are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_banded_graphics_mode::<64>();
+    /// ```
+    ///
+    /// See [`BandedGraphicsMode`] for more information.
+    pub fn into_banded_graphics_mode<const N: usize>(
+        self,
+    ) -> Ssd1306<DI, SIZE, BandedGraphicsMode<N>> {
+        #[allow(clippy::let_unit_value)]
+        let () = Ssd1306::<DI, SIZE, BandedGraphicsMode<N>>::BAND_HOLDS_PAGE;
+
+        self.into_mode(BandedGraphicsMode::new())
+    }
+
+    /// Convert the display into a layered graphics mode, compositing up to `N` sprites over a
+    /// background framebuffer.
+    ///
+    /// See [`LayeredGraphicsMode`] for more information.
+    pub fn into_layered_graphics_mode<const N: usize>(
+        self,
+    ) -> Ssd1306<DI, SIZE, LayeredGraphicsMode<SIZE, N>> {
+        self.into_mode(LayeredGraphicsMode::new())
+    }
+
+    /// Convert the display into a grayscale mode, showing 4 levels of gray by cycling through two
+    /// bitplanes.
+    ///
+    /// See [`GrayscaleMode`] for more information.
+    pub fn into_grayscale_mode(self) -> Ssd1306<DI, SIZE, GrayscaleMode<SIZE>> {
+        self.into_mode(GrayscaleMode::new())
+    }
+
     /// Convert the display into a text-only, terminal-like mode.
     ///
     /// See [`TerminalMode`] for more information.
     pub fn into_terminal_mode(self) -> Ssd1306<DI, SIZE, TerminalMode> {
         self.into_mode(TerminalMode::new())
     }
+
+    /// Convert the display into a text-only, terminal-like mode that draws text with `FONT`.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::Font8x16, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode_with_font::<Font8x16>();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// for c in "Hello".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    /// ```
+    ///
+    /// See [`TerminalMode`] for more information.
+    pub fn into_terminal_mode_with_font<FONT>(self) -> Ssd1306<DI, SIZE, TerminalMode<FONT>> {
+        self.into_mode(TerminalMode::new())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<DI, SIZE, MODE> Ssd1306Async<DI, SIZE, MODE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    /// Convert the display into a double buffered graphics mode, supporting
+    /// [embedded-graphics](https://crates.io/crates/embedded-graphics).
+    ///
+    /// See [`DoubleBufferedGraphicsModeAsync`] for more information.
+    pub fn into_double_buffered_graphics_mode(
+        self,
+    ) -> Ssd1306Async<DI, SIZE, DoubleBufferedGraphicsModeAsync<SIZE>> {
+        self.into_mode(DoubleBufferedGraphicsModeAsync::new())
+    }
 }
 
 #[maybe_async_cfg::maybe(
@@ -254,7 +384,7 @@ where
         Command::AddressMode(mode).send(&mut self.interface).await?;
 
         self.size.configure(&mut self.interface).await?;
-        self.set_rotation(rotation).await?;
+        self.send_rotation(rotation).await?;
 
         self.set_brightness(Brightness::default()).await?;
         Command::VcomhDeselect(VcomhLevel::Auto)
@@ -340,89 +470,83 @@ where
         self.rotation
     }
 
-    /// Set the display rotation.
-    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+    /// Store the display rotation and configure the segment and COM remapping for it and the
+    /// current flips.
+    ///
+    /// Each mode exposes this as `set_rotation`, as some modes need to update their own state.
+    /// Custom display modes can call this to implement their own `set_rotation`.
+    pub async fn send_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
         self.rotation = rotation;
 
-        match rotation {
-            DisplayRotation::Rotate0 => {
-                Command::SegmentRemap(true)
-                    .send(&mut self.interface)
-                    .await?;
-                Command::ReverseComDir(true)
-                    .send(&mut self.interface)
-                    .await?;
-            }
-            DisplayRotation::Rotate90 => {
-                Command::SegmentRemap(false)
-                    .send(&mut self.interface)
-                    .await?;
-                Command::ReverseComDir(true)
-                    .send(&mut self.interface)
-                    .await?;
-            }
-            DisplayRotation::Rotate180 => {
-                Command::SegmentRemap(false)
-                    .send(&mut self.interface)
-                    .await?;
-                Command::ReverseComDir(false)
-                    .send(&mut self.interface)
-                    .await?;
-            }
-            DisplayRotation::Rotate270 => {
-                Command::SegmentRemap(true)
-                    .send(&mut self.interface)
-                    .await?;
-                Command::ReverseComDir(false)
-                    .send(&mut self.interface)
-                    .await?;
-            }
+        let (segment_remap, reverse_com_dir) = self.remapping();
+
+        Command::SegmentRemap(segment_remap)
+            .send(&mut self.interface)
+            .await?;
+        Command::ReverseComDir(reverse_com_dir)
+            .send(&mut self.interface)
+            .await
+    }
+
+    /// Get whether segments and COM outputs are remapped for the current rotation and flips, as
+    /// `(segment_remap, reverse_com_dir)`.
+    fn remapping(&self) -> (bool, bool) {
+        let (segment_remap, reverse_com_dir) = match self.rotation {
+            DisplayRotation::Rotate0 => (true, true),
+            DisplayRotation::Rotate90 => (false, true),
+            DisplayRotation::Rotate180 => (false, false),
+            DisplayRotation::Rotate270 => (true, false),
         };
 
-        Ok(())
+        // Segments run along the X axis of the display when it is not rotated by 90 or 270
+        // degrees, and COM outputs along its Y axis
+        let (flip_segments, flip_coms) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (self.flip_x, self.flip_y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (self.flip_y, self.flip_x),
+        };
+
+        (segment_remap ^ flip_segments, reverse_com_dir ^ flip_coms)
+    }
+
+    /// Get the horizontal and vertical flips, as `(flip_x, flip_y)`.
+    pub fn flip(&self) -> (bool, bool) {
+        (self.flip_x, self.flip_y)
+    }
+
+    /// Flip the display horizontally and/or vertically, for example for modules mounted behind
+    /// a mirror or seen through a prism.
+    ///
+    /// The flips are applied after the rotation, so `flip_x` always mirrors the X axis and
+    /// `flip_y` the Y axis of the rotated display. They stay in effect when the rotation is
+    /// changed or the display is initialised again. The contents of the display RAM are not
+    /// changed, so the current image is flipped immediately.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.set_flip(false, true).unwrap();
+    /// display.init().unwrap();
+    ///
+    /// display.set_rotation(DisplayRotation::Rotate0).unwrap();
+    /// assert_eq!(display.flip(), (false, true));
+    /// ```
+    pub async fn set_flip(&mut self, flip_x: bool, flip_y: bool) -> Result<(), DisplayError> {
+        self.flip_x = flip_x;
+        self.flip_y = flip_y;
+
+        self.send_rotation(self.rotation).await
     }
 
     /// Set mirror enabled/disabled.
+    ///
+    /// This mirrors the X axis of the display, like [`set_flip`](Self::set_flip) with `flip_x`
+    /// set to `mirror`, and keeps the current vertical flip.
     pub async fn set_mirror(&mut self, mirror: bool) -> Result<(), DisplayError> {
-        if mirror {
-            match self.rotation {
-                DisplayRotation::Rotate0 => {
-                    Command::SegmentRemap(false)
-                        .send(&mut self.interface)
-                        .await?;
-                    Command::ReverseComDir(true)
-                        .send(&mut self.interface)
-                        .await?;
-                }
-                DisplayRotation::Rotate90 => {
-                    Command::SegmentRemap(false)
-                        .send(&mut self.interface)
-                        .await?;
-                    Command::ReverseComDir(false)
-                        .send(&mut self.interface)
-                        .await?;
-                }
-                DisplayRotation::Rotate180 => {
-                    Command::SegmentRemap(true)
-                        .send(&mut self.interface)
-                        .await?;
-                    Command::ReverseComDir(false)
-                        .send(&mut self.interface)
-                        .await?;
-                }
-                DisplayRotation::Rotate270 => {
-                    Command::SegmentRemap(true)
-                        .send(&mut self.interface)
-                        .await?;
-                    Command::ReverseComDir(true)
-                        .send(&mut self.interface)
-                        .await?;
-                }
-            };
-        } else {
-            self.set_rotation(self.rotation).await?;
-        }
-        Ok(())
+        self.set_flip(mirror, self.flip_y).await
     }
 
     /// Change the display brightness.
@@ -448,14 +572,23 @@ where
         &mut self,
         start: (u8, u8),
         end: (u8, u8),
+    ) -> Result<(), DisplayError> {
+        Self::send_draw_area(&mut self.interface, self.addr_mode, start, end).await
+    }
+
+    async fn send_draw_area(
+        interface: &mut DI,
+        addr_mode: AddrMode,
+        start: (u8, u8),
+        end: (u8, u8),
     ) -> Result<(), DisplayError> {
         Command::ColumnAddress(start.0, end.0.saturating_sub(1))
-            .send(&mut self.interface)
+            .send(interface)
             .await?;
 
-        if self.addr_mode != AddrMode::Page {
+        if addr_mode != AddrMode::Page {
             Command::PageAddress(start.1.into(), (end.1.saturating_sub(1)).into())
-                .send(&mut self.interface)
+                .send(interface)
                 .await?;
         }
 
@@ -484,6 +617,154 @@ where
         Command::Invert(invert).send(&mut self.interface).await
     }
 
+    /// The first driver column of the display area for the current rotation and flips.
+    fn column_offset(&self) -> u8 {
+        let (segment_remap, _) = self.remapping();
+
+        if segment_remap {
+            SIZE::OFFSETX
+        } else {
+            // If segment remapping is flipped, we need to calculate
+            // the offset from the other edge of the display.
+            SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
+        }
+    }
+
+    /// The addressing mode used to send a framebuffer laid out by [`BufferedGraphicsMode`] for
+    /// `rotation`.
+    fn graphics_addr_mode(rotation: DisplayRotation) -> AddrMode {
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => AddrMode::Horizontal,
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => AddrMode::Vertical,
+        }
+    }
+
+    /// Send the part of a framebuffer laid out by [`BufferedGraphicsMode`] for `rotation` that is
+    /// covered by `area` to the display, with the display area starting at column `offset_x`.
+    ///
+    /// The display is switched to the addressing mode matching the buffer layout if needed.
+    async fn flush_area(
+        interface: &mut DI,
+        addr_mode: &mut AddrMode,
+        rotation: DisplayRotation,
+        offset_x: u8,
+        buffer: &[u8],
+        area: DirtyArea,
+    ) -> Result<(), DisplayError> {
+        // Nothing to do if no pixels have changed since the last update
+        if area.is_empty() {
+            return Ok(());
+        }
+
+        let mode = Self::graphics_addr_mode(rotation);
+        if *addr_mode != mode {
+            Command::AddressMode(mode).send(interface).await?;
+            *addr_mode = mode;
+        }
+
+        let (width, height) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
+        };
+
+        // Determine which bytes need to be sent
+        let disp_min_x = area.min_x;
+        let disp_min_y = area.min_y;
+
+        let (disp_max_x, disp_max_y) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                ((area.max_x + 1).min(width), (area.max_y | 7).min(height))
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                ((area.max_x | 7).min(width), (area.max_y + 1).min(height))
+            }
+        };
+
+        // Tell the display to update only the part that has changed
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                Self::send_draw_area(
+                    interface,
+                    mode,
+                    (disp_min_x + offset_x, disp_min_y + SIZE::OFFSETY),
+                    (disp_max_x + offset_x, disp_max_y + SIZE::OFFSETY),
+                )
+                .await?;
+
+                Self::flush_buffer_chunks(
+                    interface,
+                    buffer,
+                    width as usize,
+                    (disp_min_x, disp_min_y),
+                    (disp_max_x, disp_max_y),
+                )
+                .await
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                Self::send_draw_area(
+                    interface,
+                    mode,
+                    (disp_min_y + offset_x, disp_min_x + SIZE::OFFSETY),
+                    (disp_max_y + offset_x, disp_max_x + SIZE::OFFSETY),
+                )
+                .await?;
+
+                Self::flush_buffer_columns(
+                    interface,
+                    buffer,
+                    (disp_min_y, disp_min_x / 8),
+                    (disp_max_y, disp_max_x / 8),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Send the pages from `upper_left.1` to `lower_right.1` (inclusive) of the columns from
+    /// `upper_left.0` to `lower_right.0` (exclusive) of a buffer stored one column after the
+    /// other, as expected by vertical addressing mode.
+    async fn flush_buffer_columns(
+        interface: &mut DI,
+        buffer: &[u8],
+        upper_left: (u8, u8),
+        lower_right: (u8, u8),
+    ) -> Result<(), DisplayError> {
+        const BYTES_PER_BATCH: usize = 64;
+
+        let num_pages = usize::from(SIZE::HEIGHT / 8);
+        let (col_start, col_end) = (usize::from(upper_left.0), usize::from(lower_right.0));
+        let (page_start, page_end) = (usize::from(upper_left.1), usize::from(lower_right.1));
+
+        // Whole columns follow each other in the buffer, so they can be sent in one go
+        if page_start == 0 && page_end + 1 == num_pages {
+            return interface
+                .send_data(U8(&buffer[col_start * num_pages..col_end * num_pages]))
+                .await;
+        }
+
+        // Otherwise gather the changed part of each column into batches
+        let mut batch = [0u8; BYTES_PER_BATCH];
+        let mut len = 0;
+
+        for column in buffer[col_start * num_pages..col_end * num_pages].chunks(num_pages) {
+            for &byte in &column[page_start..=page_end] {
+                batch[len] = byte;
+                len += 1;
+
+                if len == BYTES_PER_BATCH {
+                    interface.send_data(U8(&batch)).await?;
+                    len = 0;
+                }
+            }
+        }
+
+        if len > 0 {
+            interface.send_data(U8(&batch[..len])).await?;
+        }
+
+        Ok(())
+    }
+
     async fn flush_buffer_chunks(
         interface: &mut DI,
         buffer: &[u8],
@@ -0,0 +1,348 @@
+//! Banded graphics mode.
+
+use crate::{command::AddrMode, rotation::DisplayRotation, size::DisplaySize, Ssd1306};
+#[cfg(feature = "async")]
+use crate::{size::DisplaySizeAsync, Ssd1306Async};
+#[cfg(feature = "async")]
+use display_interface::AsyncWriteOnlyDataCommand;
+use display_interface::{DataFormat::U8, DisplayError, WriteOnlyDataCommand};
+
+use super::DisplayConfig;
+#[cfg(feature = "async")]
+use super::DisplayConfigAsync;
+
+/// Banded graphics mode.
+///
+/// This mode keeps only a band of `N` bytes in system memory instead of a framebuffer for the
+/// whole display. Each band covers `N / SIZE::WIDTH` pages, i.e. rows 8 pixels high, so a 128x64px
+/// display can be drawn using as little as 128 bytes of RAM.
+///
+/// A frame is drawn with [`draw_bands`](Ssd1306::draw_bands), which calls a closure once per
+/// band. The closure draws the whole frame into the [`Band`] it is given, which discards every
+/// pixel outside of the band, and the band is sent to the display as soon as the closure returns.
+/// This trades CPU time for memory: the scene is drawn `SIZE::HEIGHT / 8 / (N / SIZE::WIDTH)`
+/// times per frame.
+#[derive(Clone, Debug)]
+pub struct BandedGraphicsMode<const N: usize> {
+    buffer: [u8; N],
+}
+
+impl<const N: usize> BandedGraphicsMode<N> {
+    /// Create a new banded graphics mode instance.
+    pub(crate) fn new() -> Self {
+        Self { buffer: [0; N] }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async", idents(DisplaySize(async = "DisplaySizeAsync")))
+)]
+impl<DI, SIZE, const N: usize> Ssd1306<DI, SIZE, BandedGraphicsMode<N>>
+where
+    SIZE: DisplaySize,
+{
+    /// Fails to compile when a band of `N` bytes cannot hold a single page of the display.
+    pub(crate) const BAND_HOLDS_PAGE: () = assert!(
+        N >= SIZE::WIDTH as usize,
+        "band buffer must hold at least one page"
+    );
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            DisplayConfig(async = "DisplayConfigAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> DisplayConfig for Ssd1306<DI, SIZE, BandedGraphicsMode<N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Error = DisplayError;
+
+    /// Set the display rotation.
+    async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+        self.set_rotation(rot).await
+    }
+
+    /// Initialise the display in horizontal addressing mode.
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        self.init_with_addr_mode(AddrMode::Horizontal).await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> Ssd1306<DI, SIZE, BandedGraphicsMode<N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Set the display rotation.
+    ///
+    /// The next call to [`draw_bands`](Self::draw_bands) draws the frame in the new orientation.
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        self.send_rotation(rotation).await
+    }
+
+    /// Draw a frame one band at a time.
+    ///
+    /// `draw` is called once for each band, from the top of the display memory to the bottom,
+    /// and must draw the whole frame every time. Only the pixels inside the band are kept, and
+    /// each band is sent to the display before `draw` is called for the next one.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Circle, PrimitiveStyle},
+    /// };
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// // Keep a single page of 128 bytes in memory
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_banded_graphics_mode::<128>();
+    /// display.init().unwrap();
+    ///
+    /// display
+    ///     .draw_bands(|band| {
+    ///         Circle::new(Point::new(40, 8), 48)
+    ///             .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///             .draw(band)
+    ///     })
+    ///     .unwrap();
+    /// ```
+    pub async fn draw_bands<F>(&mut self, mut draw: F) -> Result<(), DisplayError>
+    where
+        F: FnMut(&mut Band<'_>) -> Result<(), DisplayError>,
+    {
+        let total_pages = SIZE::HEIGHT / 8;
+        let band_pages = (N / SIZE::WIDTH as usize).min(total_pages.into()) as u8;
+        let offset_x = self.column_offset();
+
+        for first_page in (0..total_pages).step_by(band_pages.into()) {
+            let pages = band_pages.min(total_pages - first_page);
+            let len = usize::from(pages) * SIZE::WIDTH as usize;
+
+            let mut band = Band {
+                buffer: &mut self.mode.buffer[..len],
+                first_page,
+                pages,
+                width: SIZE::WIDTH,
+                height: SIZE::HEIGHT,
+                rotation: self.rotation,
+            };
+            band.buffer.fill(0);
+            draw(&mut band)?;
+
+            Self::send_draw_area(
+                &mut self.interface,
+                self.addr_mode,
+                (offset_x, first_page * 8 + SIZE::OFFSETY),
+                (
+                    offset_x + SIZE::WIDTH,
+                    (first_page + pages) * 8 + SIZE::OFFSETY,
+                ),
+            )
+            .await?;
+            self.interface
+                .send_data(U8(&self.mode.buffer[..len]))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A band of the display being drawn in [`BandedGraphicsMode`].
+///
+/// Coordinates are those of the whole display, taking into account its rotation. Pixels outside
+/// of the band are ignored.
+#[derive(Debug)]
+pub struct Band<'a> {
+    buffer: &'a mut [u8],
+    first_page: u8,
+    pages: u8,
+    width: u8,
+    height: u8,
+    rotation: DisplayRotation,
+}
+
+impl Band<'_> {
+    /// Get display dimensions, taking into account the current rotation of the display.
+    pub fn dimensions(&self) -> (u8, u8) {
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (self.width, self.height),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (self.height, self.width),
+        }
+    }
+
+    /// Get the area of the display covered by this band, as its top left corner and its size.
+    ///
+    /// Drawing code can use this to skip anything that lies outside of the band.
+    pub fn area(&self) -> ((u8, u8), (u8, u8)) {
+        let (width, height) = self.dimensions();
+        let (start, len) = (self.first_page * 8, self.pages * 8);
+
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => ((0, start), (width, len)),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => ((start, 0), (len, height)),
+        }
+    }
+
+    /// Turn a pixel on or off. If the X and Y coordinates are outside of the band, this method
+    /// call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        let (width, height) = self.dimensions();
+        if x >= width.into() || y >= height.into() {
+            return;
+        }
+
+        // Column and row in display memory
+        let (col, row) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x),
+        };
+
+        let Some(page) = (row / 8)
+            .checked_sub(self.first_page.into())
+            .filter(|page| *page < self.pages.into())
+        else {
+            return;
+        };
+
+        let byte = &mut self.buffer[page as usize * self.width as usize + col as usize];
+        let bit = 1 << (row % 8);
+
+        if value {
+            *byte |= bit;
+        } else {
+            *byte &= !bit;
+        }
+    }
+}
+
+#[cfg(feature = "graphics")]
+use super::{fill_pages, DrawMode};
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Point, Size},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+impl Band<'_> {
+    /// Get the area of the display covered by this band as a [`Rectangle`].
+    pub fn bounding_rectangle(&self) -> Rectangle {
+        let ((x, y), (width, height)) = self.area();
+
+        Rectangle::new(
+            Point::new(x.into(), y.into()),
+            Size::new(width.into(), height.into()),
+        )
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl DrawTarget for Band<'_> {
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_rectangle();
+
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    /// Fill the part of `area` inside the band with `color`, writing whole bytes where possible.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(StubInterface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_banded_graphics_mode::<256>();
+    /// display.init().unwrap();
+    ///
+    /// let area = Rectangle::new(Point::new(3, 5), Size::new(20, 21));
+    /// display
+    ///     .draw_bands(|band| band.fill_solid(&area, BinaryColor::On))
+    ///     .unwrap();
+    /// ```
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_rectangle());
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+
+        let (x, y) = (area.top_left.x as usize, area.top_left.y as usize);
+        let (width, height) = (area.size.width as usize, area.size.height as usize);
+
+        // Column and rows in display memory
+        let (col, row, cols, rows) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y, width, height),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x, height, width),
+        };
+
+        // The band holds its pages one after the other, starting at `first_page`
+        let first_row = usize::from(self.first_page) * 8;
+        let band_width = usize::from(self.width);
+
+        fill_pages(
+            self.buffer,
+            |col, page| page * band_width + col,
+            1,
+            (col, cols),
+            row - first_row..row - first_row + rows,
+            color.is_on(),
+            DrawMode::Replace,
+        );
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.buffer.fill(if color.is_on() { 0xff } else { 0 });
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl OriginDimensions for Band<'_> {
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(w.into(), h.into())
+    }
+}
@@ -1,7 +1,8 @@
 //! Buffered graphics mode.
 
+use core::marker::PhantomData;
+
 use crate::{
-    command::AddrMode,
     rotation::DisplayRotation,
     size::{DisplaySize, NewZeroed},
     Ssd1306,
@@ -12,26 +13,203 @@ use crate::{size::DisplaySizeAsync, Ssd1306Async};
 use display_interface::AsyncWriteOnlyDataCommand;
 use display_interface::{DisplayError, WriteOnlyDataCommand};
 
+/// Bounding box of the pixels changed since the last flush, in rotated display coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct DirtyArea {
+    pub(crate) min_x: u8,
+    pub(crate) max_x: u8,
+    pub(crate) min_y: u8,
+    pub(crate) max_y: u8,
+}
+
+impl DirtyArea {
+    /// An area containing no pixels.
+    pub(crate) const fn empty() -> Self {
+        Self {
+            min_x: 255,
+            max_x: 0,
+            min_y: 255,
+            max_y: 0,
+        }
+    }
+
+    /// An area covering a whole display of the given (rotated) dimensions.
+    pub(crate) const fn full(width: u8, height: u8) -> Self {
+        Self {
+            min_x: 0,
+            max_x: width - 1,
+            min_y: 0,
+            max_y: height - 1,
+        }
+    }
+
+    /// Whether no pixels have changed.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.max_x < self.min_x || self.max_y < self.min_y
+    }
+
+    /// Grow the area to include the given pixel.
+    pub(crate) fn include(&mut self, x: u8, y: u8) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// The part of the area that lies within the given bounds, which are inclusive.
+    pub(crate) fn intersection(self, (min_x, min_y): (u8, u8), (max_x, max_y): (u8, u8)) -> Self {
+        Self {
+            min_x: self.min_x.max(min_x),
+            max_x: self.max_x.min(max_x),
+            min_y: self.min_y.max(min_y),
+            max_y: self.max_y.min(max_y),
+        }
+    }
+
+    /// The smallest area containing both `self` and `other`.
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self {
+            min_x: self.min_x.min(other.min_x),
+            max_x: self.max_x.max(other.max_x),
+            min_y: self.min_y.min(other.min_y),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+}
+
+/// Fill `cols` columns starting at column `col` within the display memory rows `rows` with
+/// `value`, combining it with the bytes of `buffer` using `draw_mode`.
+///
+/// `byte_index` gives the index of the byte holding a page of a column, and `stride` the distance
+/// between the bytes of neighbouring columns in the same page. Whole bytes are written where all 8
+/// rows of a page are covered, and masked bytes at the top and bottom edges.
+#[cfg(feature = "graphics")]
+pub(crate) fn fill_pages(
+    buffer: &mut [u8],
+    byte_index: impl Fn(usize, usize) -> usize,
+    stride: usize,
+    (col, cols): (usize, usize),
+    rows: core::ops::Range<usize>,
+    value: bool,
+    draw_mode: DrawMode,
+) {
+    let bits = if value { 0xff } else { 0x00 };
+
+    let mut row = rows.start;
+    while row < rows.end {
+        let page = row / 8;
+        let page_row_end = rows.end.min((page + 1) * 8);
+
+        let mask = (0xff << (row % 8)) & (0xff >> (8 - (page_row_end - page * 8)));
+
+        let start = byte_index(col, page);
+
+        if stride == 1 && mask == 0xff && draw_mode == DrawMode::Replace {
+            buffer[start..start + cols].fill(bits);
+        } else {
+            for byte in buffer[start..].iter_mut().step_by(stride).take(cols) {
+                *byte = draw_mode.apply(*byte, bits, mask);
+            }
+        }
+
+        row = page_row_end;
+    }
+}
+
+/// Transpose the `rows` x `cols` matrix of bytes stored row by row in `buffer` in place.
+fn transpose_bytes(buffer: &mut [u8], rows: usize, cols: usize) {
+    let len = rows * cols;
+    if len < 2 {
+        return;
+    }
+
+    // The byte at index `i` moves to index `i * rows mod (len - 1)`, except for the first and the
+    // last byte which stay in place. Follow each cycle of that permutation once, starting from
+    // its smallest index.
+    let next = |i: usize| i * rows % (len - 1);
+
+    for start in 1..len - 1 {
+        let mut i = next(start);
+        while i > start {
+            i = next(i);
+        }
+        if i < start {
+            continue;
+        }
+
+        let mut byte = buffer[start];
+        let mut i = next(start);
+        loop {
+            core::mem::swap(&mut byte, &mut buffer[i]);
+            if i == start {
+                break;
+            }
+            i = next(i);
+        }
+    }
+}
+
+/// How pixels drawn in [`BufferedGraphicsMode`] are combined with the contents of the framebuffer.
+///
+/// Use [`set_draw_mode`](Ssd1306::set_draw_mode) to change the mode of a display.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DrawMode {
+    /// Overwrite pixels with the drawn color (default)
+    #[default]
+    Replace,
+    /// Turn on pixels drawn as on, leaving all others unchanged
+    Or,
+    /// Turn off pixels drawn as on, leaving all others unchanged
+    AndNot,
+    /// Invert pixels drawn as on, leaving all others unchanged. Drawing the same thing twice
+    /// restores the original contents of the framebuffer.
+    Xor,
+}
+
+impl DrawMode {
+    /// Combine the `bits` selected by `mask` with `byte`.
+    fn apply(self, byte: u8, bits: u8, mask: u8) -> u8 {
+        let bits = bits & mask;
+
+        match self {
+            DrawMode::Replace => byte & !mask | bits,
+            DrawMode::Or => byte | bits,
+            DrawMode::AndNot => byte & !bits,
+            DrawMode::Xor => byte ^ bits,
+        }
+    }
+}
+
 /// Buffered graphics mode.
 ///
 /// This mode keeps a pixel buffer in system memory, up to 1024 bytes for 128x64px displays. This
 /// buffer is drawn to by [`set_pixel`](Ssd1306::set_pixel) commands or
 /// [`embedded-graphics`](https://docs.rs/embedded-graphics) commands. The display can then be
 /// updated using the [`flush`](Ssd1306::flush) method.
+///
+/// By default the buffer is stored inline in the mode, and so moves around with the [`Ssd1306`]
+/// value. On MCUs with little stack space the buffer can instead be provided by the caller, for
+/// example from a `static`, using
+/// [`into_buffered_graphics_mode_with_buffer`](Ssd1306::into_buffered_graphics_mode_with_buffer).
+/// In that case `BUF` is a `&mut SIZE::Buffer`.
+///
+/// For 90 and 270 degree rotations the buffer holds the rows of the rotated display one after
+/// the other, with each byte covering 8 horizontal pixels and the leftmost pixel in the least
+/// significant bit. It is sent to the display in vertical addressing mode, so a full update is a
+/// single transfer.
 #[maybe_async_cfg::maybe(
     sync(keep_self),
     async(feature = "async", idents(DisplaySize(async = "DisplaySizeAsync")))
 )]
 #[derive(Clone, Debug)]
-pub struct BufferedGraphicsMode<SIZE>
+pub struct BufferedGraphicsMode<SIZE, BUF = <SIZE as DisplaySize>::Buffer>
 where
     SIZE: DisplaySize,
 {
-    buffer: SIZE::Buffer,
-    min_x: u8,
-    max_x: u8,
-    min_y: u8,
-    max_y: u8,
+    pub(crate) buffer: BUF,
+    pub(crate) dirty: DirtyArea,
+    pub(crate) draw_mode: DrawMode,
+    size: PhantomData<SIZE>,
 }
 
 #[maybe_async_cfg::maybe(
@@ -44,14 +222,344 @@ where
 {
     /// Create a new buffered graphics mode instance.
     pub(crate) fn new() -> Self {
+        Self::with_buffer(NewZeroed::new_zeroed())
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async", idents(DisplaySize(async = "DisplaySizeAsync")))
+)]
+impl<SIZE, BUF> BufferedGraphicsMode<SIZE, BUF>
+where
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    /// Create a new buffered graphics mode instance drawing into the given buffer.
+    pub(crate) fn with_buffer(buffer: BUF) -> Self {
         Self {
-            buffer: NewZeroed::new_zeroed(),
-            min_x: 255,
-            max_x: 0,
-            min_y: 255,
-            max_y: 0,
+            buffer,
+            dirty: DirtyArea::empty(),
+            draw_mode: DrawMode::Replace,
+            size: PhantomData,
+        }
+    }
+
+    /// Get the buffer dimensions for the given rotation, as (width, height).
+    pub(crate) fn dimensions(rotation: DisplayRotation) -> (u8, u8) {
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
+        }
+    }
+
+    /// Move the contents of `buffer` from the layout for rotation `from` into the layout for
+    /// rotation `to`, keeping every pixel at the same logical coordinates.
+    ///
+    /// Switching between landscape and portrait transposes the buffer. Pixels that fall outside
+    /// of the display in the new orientation are cleared.
+    pub(crate) fn remap_layout(buffer: &mut [u8], from: DisplayRotation, to: DisplayRotation) {
+        let portrait = |rotation| {
+            matches!(
+                rotation,
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270
+            )
+        };
+        if portrait(from) == portrait(to) {
+            return;
+        }
+
+        let width = SIZE::WIDTH as usize;
+        let pages = usize::from(SIZE::HEIGHT / 8);
+
+        // Portrait buffers are stored one column after the other instead of one page after the
+        // other, so switch to pages first
+        if portrait(from) {
+            transpose_bytes(buffer, width, pages);
+        }
+
+        // Only the square in the top left corner of the buffer is kept
+        let side = SIZE::WIDTH.min(SIZE::HEIGHT) as usize;
+        let index = |col: usize, row: usize| (row / 8 * width + col, row % 8);
+
+        for row in 0..side {
+            for col in 0..row {
+                let (a, a_bit) = index(col, row);
+                let (b, b_bit) = index(row, col);
+                let (a_on, b_on) = (buffer[a] >> a_bit & 1, buffer[b] >> b_bit & 1);
+
+                buffer[a] = buffer[a] & !(1 << a_bit) | b_on << a_bit;
+                buffer[b] = buffer[b] & !(1 << b_bit) | a_on << b_bit;
+            }
+        }
+
+        for (page, bytes) in buffer.chunks_mut(width).enumerate() {
+            if page * 8 >= side {
+                bytes.fill(0);
+            } else {
+                bytes[side..].fill(0);
+            }
+        }
+
+        if portrait(to) {
+            transpose_bytes(buffer, pages, width);
+        }
+    }
+
+    /// Remap the buffer from the layout for rotation `from` to the layout for `to` and mark it as
+    /// changed.
+    pub(crate) fn rotate(&mut self, from: DisplayRotation, to: DisplayRotation) {
+        Self::remap_layout(self.buffer.as_mut(), from, to);
+
+        let (width, height) = Self::dimensions(to);
+        self.dirty = DirtyArea::full(width, height);
+    }
+
+    /// Fill the whole buffer with `value` and mark it as changed.
+    pub(crate) fn clear(&mut self, rotation: DisplayRotation, value: bool) {
+        self.buffer.as_mut().fill(if value { 0xff } else { 0 });
+
+        let (width, height) = Self::dimensions(rotation);
+        self.dirty = DirtyArea::full(width, height);
+    }
+
+    /// Get the byte index and bit of a pixel in the buffer layout for the given rotation, or
+    /// `None` if the coordinates are out of bounds.
+    fn pixel_index(rotation: DisplayRotation, x: u32, y: u32) -> Option<(usize, u32)> {
+        let (width, height) = Self::dimensions(rotation);
+        if x >= width.into() || y >= height.into() {
+            return None;
+        }
+
+        let (idx, bit) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
+                Self::byte_index(rotation, x as usize, y as usize / 8),
+                y % 8,
+            ),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
+                Self::byte_index(rotation, y as usize, x as usize / 8),
+                x % 8,
+            ),
+        };
+
+        Some((idx, bit))
+    }
+
+    /// Get the index of the byte holding `page` of display column `col` in the buffer layout for
+    /// the given rotation.
+    ///
+    /// For 0/180 degree rotations the buffer is stored one page after the other, as sent in
+    /// horizontal addressing mode. For 90/270 degree rotations it is stored one column after the
+    /// other, as sent in vertical addressing mode, so each row of the rotated display is a run
+    /// of bytes holding 8 horizontal pixels each.
+    pub(crate) fn byte_index(rotation: DisplayRotation, col: usize, page: usize) -> usize {
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                page * SIZE::WIDTH as usize + col
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                col * usize::from(SIZE::HEIGHT / 8) + page
+            }
         }
     }
+
+    /// Turn a pixel on or off, using the buffer layout for the given rotation. Out of bounds
+    /// coordinates are ignored.
+    pub(crate) fn set_pixel(&mut self, rotation: DisplayRotation, x: u32, y: u32, value: bool) {
+        let value = value as u8;
+        let draw_mode = self.draw_mode;
+
+        let Some((idx, bit)) = Self::pixel_index(rotation, x, y) else {
+            return;
+        };
+
+        if let Some(byte) = self.buffer.as_mut().get_mut(idx) {
+            // Keep track of max and min values
+            self.dirty.include(x as u8, y as u8);
+
+            // Set pixel value in byte
+            *byte = draw_mode.apply(*byte, value << bit, 1 << bit);
+        }
+    }
+
+    /// Draw a horizontal run of pixels starting at (x, y), using the buffer layout for the given
+    /// rotation. The run must lie within the display.
+    pub(crate) fn draw_row(
+        &mut self,
+        rotation: DisplayRotation,
+        x: u8,
+        y: u8,
+        width: u8,
+        pixels: impl IntoIterator<Item = bool>,
+    ) {
+        if width == 0 {
+            return;
+        }
+
+        self.dirty.include(x, y);
+        self.dirty.include(x + width - 1, y);
+
+        let draw_mode = self.draw_mode;
+        let buffer = self.buffer.as_mut();
+        let mut pixels = pixels.into_iter();
+
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                // Every pixel in the row is in a different byte, at the same bit
+                let start = usize::from(y / 8) * SIZE::WIDTH as usize + usize::from(x);
+                let bit = 1 << (y % 8);
+
+                for (byte, on) in buffer[start..start + usize::from(width)]
+                    .iter_mut()
+                    .zip(pixels)
+                {
+                    *byte = draw_mode.apply(*byte, if on { bit } else { 0 }, bit);
+                }
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                // Up to 8 consecutive pixels in the row share a byte, so collect them and write
+                // each byte once
+                let end = usize::from(x) + usize::from(width);
+                let mut x = usize::from(x);
+
+                while x < end {
+                    let page = x / 8;
+                    let page_end = end.min((page + 1) * 8);
+
+                    let mut mask = 0u8;
+                    let mut bits = 0u8;
+                    for bit in (x % 8)..(page_end - page * 8) {
+                        match pixels.next() {
+                            Some(on) => {
+                                mask |= 1 << bit;
+                                bits |= (on as u8) << bit;
+                            }
+                            None => break,
+                        }
+                    }
+
+                    let byte = &mut buffer[Self::byte_index(rotation, usize::from(y), page)];
+                    *byte = draw_mode.apply(*byte, bits, mask);
+
+                    x = page_end;
+                }
+            }
+        }
+    }
+
+    /// Copy an image in the display's native page format to (x, y), using the buffer layout for
+    /// the given rotation. Parts of the image outside of the display are clipped.
+    pub(crate) fn blit_page_image(
+        &mut self,
+        rotation: DisplayRotation,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+        data: &[u8],
+    ) {
+        let (disp_width, disp_height) = Self::dimensions(rotation);
+
+        // Crop the image to the whole pages contained in `data`
+        let stride = width as usize;
+        let pages = data.len().checked_div(stride).unwrap_or(0);
+        let height = u64::from(height).min(pages as u64 * 8);
+
+        // The part of the image that is visible on the display
+        let (x64, y64) = (i64::from(x), i64::from(y));
+        let col_start = (-x64).max(0);
+        let col_end = (i64::from(disp_width) - x64).min(i64::from(width));
+        let row_start = (-y64).max(0);
+        let row_end = (i64::from(disp_height) - y64).min(height as i64);
+
+        if col_start >= col_end || row_start >= row_end {
+            return;
+        }
+
+        // Everything visible fits into the display, so narrow to its coordinate space
+        let (col_start, col_end) = (col_start as i32, col_end as i32);
+        let (row_start, row_end) = (row_start as i32, row_end as i32);
+
+        let src_index = |page: i32, col: i32| page as usize * stride + col as usize;
+
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                self.dirty
+                    .include((x + col_start) as u8, (y + row_start) as u8);
+                self.dirty
+                    .include((x + col_end - 1) as u8, (y + row_end - 1) as u8);
+
+                let draw_mode = self.draw_mode;
+                let buffer = self.buffer.as_mut();
+                let disp_pages = i32::from(disp_height / 8);
+                let shift = y.rem_euclid(8);
+                let dest_col = (x + col_start) as usize;
+                let cols = (col_end - col_start) as usize;
+
+                for src_page in (row_start / 8)..((row_end + 7) / 8) {
+                    // Only copy the visible rows of this page
+                    let top = src_page * 8;
+                    let first = (row_start - top).max(0);
+                    let last = (row_end - top).min(8);
+                    let mask: u8 = (0xff << first) & (0xff >> (8 - last));
+
+                    let dest_page = (y + top).div_euclid(8);
+                    let src = &data[src_index(src_page, col_start)..][..cols];
+
+                    if shift == 0 && mask == 0xff && draw_mode == DrawMode::Replace {
+                        // Page aligned, so the image bytes can be copied as they are
+                        let start = dest_page as usize * SIZE::WIDTH as usize + dest_col;
+                        buffer[start..start + cols].copy_from_slice(src);
+                        continue;
+                    }
+
+                    // Otherwise each image byte is split across two pages of the buffer
+                    let mask = u16::from(mask) << shift;
+                    for (page, high) in [(dest_page, 0), (dest_page + 1, 8)] {
+                        let mask = (mask >> high) as u8;
+                        if mask == 0 || page < 0 || page >= disp_pages {
+                            continue;
+                        }
+
+                        let start = page as usize * SIZE::WIDTH as usize + dest_col;
+
+                        for (byte, src) in buffer[start..start + cols].iter_mut().zip(src) {
+                            let bits = ((u16::from(*src) << shift) >> high) as u8;
+                            *byte = draw_mode.apply(*byte, bits, mask);
+                        }
+                    }
+                }
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                // Each byte of the image covers 8 different bytes in the buffer, so draw the image
+                // one row at a time
+                for row in row_start..row_end {
+                    let (page, bit) = (row / 8, row % 8);
+
+                    self.draw_row(
+                        rotation,
+                        (x + col_start) as u8,
+                        (y + row) as u8,
+                        (col_end - col_start) as u8,
+                        data[src_index(page, col_start)..src_index(page, col_end)]
+                            .iter()
+                            .map(|byte| byte & (1 << bit) != 0),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Get the state of a pixel, using the buffer layout for the given rotation. Returns `None`
+    /// for out of bounds coordinates.
+    pub(crate) fn get_pixel(&self, rotation: DisplayRotation, x: u32, y: u32) -> Option<bool> {
+        let (idx, bit) = Self::pixel_index(rotation, x, y)?;
+
+        self.buffer
+            .as_ref()
+            .get(idx)
+            .map(|byte| byte & (1 << bit) != 0)
+    }
 }
 
 #[maybe_async_cfg::maybe(
@@ -66,16 +574,17 @@ where
         )
     )
 )]
-impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, BUF> DisplayConfig for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
 {
     type Error = DisplayError;
 
     /// Set the display rotation
     ///
-    /// This method resets the cursor but does not clear the screen.
+    /// The framebuffer is remapped to the new rotation and marked as changed.
     async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
         self.set_rotation(rot).await
     }
@@ -83,7 +592,8 @@ where
     /// Initialise and clear the display in graphics mode.
     async fn init(&mut self) -> Result<(), DisplayError> {
         self.clear_impl(false);
-        self.init_with_addr_mode(AddrMode::Horizontal).await
+        self.init_with_addr_mode(Self::graphics_addr_mode(self.rotation))
+            .await
     }
 }
 
@@ -94,23 +604,68 @@ where
         idents(
             DisplaySize(async = "DisplaySizeAsync"),
             WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
-            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync"),
+            Viewport(async = "ViewportAsync")
         )
     )
 )]
-impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, BUF> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
 {
     fn clear_impl(&mut self, value: bool) {
-        self.mode.buffer.as_mut().fill(if value { 0xff } else { 0 });
+        self.mode.clear(self.rotation, value);
+    }
 
-        let (width, height) = self.dimensions();
-        self.mode.min_x = 0;
-        self.mode.max_x = width - 1;
-        self.mode.min_y = 0;
-        self.mode.max_y = height - 1;
+    /// Set the display rotation.
+    ///
+    /// The framebuffer is remapped to the layout of the new rotation and marked as changed, so
+    /// the next [`flush`](Self::flush) shows the same image in the new orientation. When
+    /// switching between landscape and portrait, pixels that fall outside of the rotated display
+    /// are discarded.
+    ///
+    /// ```rust
+    /// use ssd1306::{prelude::*, test_helpers::SimulatedInterface, Ssd1306};
+    ///
+    /// let mut buffer = [0u8; 1024];
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_buffered_graphics_mode_with_buffer(&mut buffer);
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(3, 10, true);
+    /// display.set_pixel(60, 2, true);
+    /// // Outside of the display once it is rotated
+    /// display.set_pixel(100, 5, true);
+    ///
+    /// display.set_rotation(DisplayRotation::Rotate90).unwrap();
+    /// display.flush().unwrap();
+    ///
+    /// let interface = display.release();
+    ///
+    /// // The rotated buffer holds one row of 64 pixels after the other, 8 pixels per byte
+    /// let mut expected = [0u8; 1024];
+    /// expected[10 * 8] = 1 << 3;
+    /// expected[2 * 8 + 7] = 1 << 4;
+    /// assert_eq!(buffer, expected);
+    ///
+    /// // The panel shows the pixels a quarter turn further, seen in its unrotated orientation
+    /// for y in 0..64 {
+    ///     for x in 0..128 {
+    ///         let on = (x, y) == (127 - 10, 3) || (x, y) == (127 - 2, 60);
+    ///         assert_eq!(interface.pixel::<DisplaySize128x64>(x, y), on);
+    ///     }
+    /// }
+    /// ```
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        self.mode.rotate(self.rotation, rotation);
+        self.send_rotation(rotation).await
     }
 
     /// Clear the underlying framebuffer. You need to call `disp.flush()` for any effect on the screen.
@@ -122,121 +677,285 @@ where
     ///
     /// This only updates the parts of the display that have changed since the last flush.
     pub async fn flush(&mut self) -> Result<(), DisplayError> {
-        // Nothing to do if no pixels have changed since the last update
-        if self.mode.max_x < self.mode.min_x || self.mode.max_y < self.mode.min_y {
-            return Ok(());
-        }
+        let area = core::mem::replace(&mut self.mode.dirty, DirtyArea::empty());
+        let offset_x = self.column_offset();
 
-        let (width, height) = self.dimensions();
+        Self::flush_area(
+            &mut self.interface,
+            &mut self.addr_mode,
+            self.rotation,
+            offset_x,
+            self.mode.buffer.as_mut(),
+            area,
+        )
+        .await
+    }
 
-        // Determine which bytes need to be sent
-        let disp_min_x = self.mode.min_x;
-        let disp_min_y = self.mode.min_y;
+    /// Write out the part of the framebuffer within `area` to the display.
+    ///
+    /// Like [`flush`](Self::flush), this only sends pixels that have changed, but it leaves changes
+    /// outside of `area` to be sent later. This lets each [`viewport`](Self::viewport) be updated
+    /// on its own schedule.
+    #[cfg(feature = "graphics")]
+    pub async fn flush_viewport(&mut self, area: &Rectangle) -> Result<(), DisplayError> {
+        let area = area.intersection(&BufferedGraphicsMode::<SIZE, BUF>::bounding_box(
+            self.rotation,
+        ));
 
-        let (disp_max_x, disp_max_y) = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
-                (self.mode.max_x + 1).min(width),
-                (self.mode.max_y | 7).min(height),
-            ),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
-                (self.mode.max_x | 7).min(width),
-                (self.mode.max_y + 1).min(height),
-            ),
+        let Some(bottom_right) = area.bottom_right() else {
+            return Ok(());
         };
 
-        self.mode.min_x = 255;
-        self.mode.max_x = 0;
-        self.mode.min_y = 255;
-        self.mode.max_y = 0;
-
-        // Tell the display to update only the part that has changed
-        let offset_x = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-            }
-        };
+        let dirty = self.mode.dirty;
+        let flushed = dirty.intersection(
+            (area.top_left.x as u8, area.top_left.y as u8),
+            (bottom_right.x as u8, bottom_right.y as u8),
+        );
 
-        match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                self.set_draw_area(
-                    (disp_min_x + offset_x, disp_min_y + SIZE::OFFSETY),
-                    (disp_max_x + offset_x, disp_max_y + SIZE::OFFSETY),
-                )
-                .await?;
-
-                Self::flush_buffer_chunks(
-                    &mut self.interface,
-                    self.mode.buffer.as_mut(),
-                    width as usize,
-                    (disp_min_x, disp_min_y),
-                    (disp_max_x, disp_max_y),
-                )
-                .await
-            }
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                self.set_draw_area(
-                    (disp_min_y + offset_x, disp_min_x + SIZE::OFFSETY),
-                    (disp_max_y + offset_x, disp_max_x + SIZE::OFFSETY),
-                )
-                .await?;
-
-                Self::flush_buffer_chunks(
-                    &mut self.interface,
-                    self.mode.buffer.as_mut(),
-                    height as usize,
-                    (disp_min_y, disp_min_x),
-                    (disp_max_y, disp_max_x),
-                )
-                .await
-            }
+        // Changes are tracked as a single bounding box, so they can only be forgotten when the
+        // whole box has been sent
+        if flushed == dirty {
+            self.mode.dirty = DirtyArea::empty();
         }
+
+        let offset_x = self.column_offset();
+
+        Self::flush_area(
+            &mut self.interface,
+            &mut self.addr_mode,
+            self.rotation,
+            offset_x,
+            self.mode.buffer.as_mut(),
+            flushed,
+        )
+        .await
     }
 
     /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
     /// coordinates are out of the bounds of the display, this method call is a noop.
+    ///
+    /// The pixel is combined with the framebuffer according to the current
+    /// [`draw_mode`](Ssd1306::draw_mode).
     pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
-        let value = value as u8;
-        let rotation = self.rotation;
+        self.mode.set_pixel(self.rotation, x, y, value);
+    }
 
-        let (idx, bit) = match rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                let idx = ((y as usize) / 8 * SIZE::WIDTH as usize) + (x as usize);
-                let bit = y % 8;
+    /// Get the current draw mode.
+    pub fn draw_mode(&self) -> DrawMode {
+        self.mode.draw_mode
+    }
 
-                (idx, bit)
-            }
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                let idx = ((x as usize) / 8 * SIZE::WIDTH as usize) + (y as usize);
-                let bit = x % 8;
+    /// Set how pixels drawn with [`set_pixel`](Ssd1306::set_pixel),
+    /// [`blit_page_image`](Ssd1306::blit_page_image) or
+    /// [`embedded-graphics`](https://docs.rs/embedded-graphics) are combined with the framebuffer.
+    /// Clearing the framebuffer is not affected by the draw mode.
+    ///
+    /// Use [`DrawMode::Xor`] to draw cursors and highlights that can be removed again by drawing
+    /// them a second time:
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::DrawMode, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(3, 4, true);
+    /// display.set_draw_mode(DrawMode::Xor);
+    ///
+    /// display.set_pixel(3, 4, true);
+    /// assert_eq!(display.get_pixel(3, 4), Some(false));
+    ///
+    /// display.set_pixel(3, 4, true);
+    /// assert_eq!(display.get_pixel(3, 4), Some(true));
+    /// ```
+    pub fn set_draw_mode(&mut self, draw_mode: DrawMode) {
+        self.mode.draw_mode = draw_mode;
+    }
 
-                (idx, bit)
-            }
-        };
+    /// Invert all pixels of the framebuffer within `area`, regardless of the current draw mode.
+    /// Parts of `area` outside of the display are ignored.
+    #[cfg(feature = "graphics")]
+    pub fn invert_region(&mut self, area: &Rectangle) {
+        self.mode.invert_region(self.rotation, area);
+    }
 
-        if let Some(byte) = self.mode.buffer.as_mut().get_mut(idx) {
-            // Keep track of max and min values
-            self.mode.min_x = self.mode.min_x.min(x as u8);
-            self.mode.max_x = self.mode.max_x.max(x as u8);
+    /// Copy the pixels of the framebuffer within `src` so that the top left corner of `src` ends
+    /// up at `dst`, regardless of the current draw mode. The regions may overlap.
+    ///
+    /// Parts of `src` outside of the display, and pixels that would be copied to outside of the
+    /// display, are ignored. Page aligned regions are copied a byte at a time.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// use embedded_graphics::{prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(StubInterface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 20, true);
+    ///
+    /// // Move a block 3 pixels down, onto itself
+    /// display.copy_region(&Rectangle::new(Point::new(0, 16), Size::new(32, 16)), Point::new(0, 19));
+    ///
+    /// assert_eq!(display.get_pixel(10, 23), Some(true));
+    /// ```
+    #[cfg(feature = "graphics")]
+    pub fn copy_region(&mut self, src: &Rectangle, dst: Point) {
+        self.mode.copy_region(self.rotation, src, dst);
+    }
 
-            self.mode.min_y = self.mode.min_y.min(y as u8);
-            self.mode.max_y = self.mode.max_y.max(y as u8);
+    /// Move the pixels of the framebuffer within `area` by `dx` pixels to the right and `dy`
+    /// pixels down, regardless of the current draw mode. Pixels moved out of `area` are
+    /// discarded and the pixels uncovered by the move are set to `fill`.
+    ///
+    /// Parts of `area` outside of the display are ignored.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(5, 60, true);
+    ///
+    /// // Scroll a log up by one line of 8 pixels high text
+    /// display.scroll_region(&display.bounding_box(), 0, -8, BinaryColor::Off);
+    ///
+    /// assert_eq!(display.get_pixel(5, 52), Some(true));
+    /// assert_eq!(display.get_pixel(5, 60), Some(false));
+    /// ```
+    #[cfg(feature = "graphics")]
+    pub fn scroll_region(&mut self, area: &Rectangle, dx: i32, dy: i32, fill: BinaryColor) {
+        self.mode.scroll_region(self.rotation, area, dx, dy, fill);
+    }
 
-            // Set pixel value in byte
-            // Ref this comment https://stackoverflow.com/questions/47981/how-do-you-set-clear-and-toggle-a-single-bit#comment46654671_47990
-            *byte = *byte & !(1 << bit) | (value << bit);
+    /// Get a [`DrawTarget`] for the part of the framebuffer within `area`.
+    ///
+    /// The viewport has its own origin at the top left corner of `area` and discards everything
+    /// drawn outside of it, so parts of the screen can be handed to different drawing code without
+    /// them drawing over each other. Pixels drawn to parts of `area` outside of the display are
+    /// discarded.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use embedded_graphics::{
+    ///     pixelcolor::BinaryColor,
+    ///     prelude::*,
+    ///     primitives::{Line, PrimitiveStyle, Rectangle},
+    /// };
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// let status_bar = Rectangle::new(Point::zero(), Size::new(128, 8));
+    /// let main_pane = Rectangle::new(Point::new(0, 8), Size::new(128, 56));
+    ///
+    /// // Draw a line across the whole main pane. Its last pixel falls outside of the pane and is
+    /// // discarded.
+    /// Line::new(Point::new(0, 0), Point::new(0, 56))
+    ///     .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 1))
+    ///     .draw(&mut display.viewport(&main_pane))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(0, 8), Some(true));
+    /// assert_eq!(display.get_pixel(0, 63), Some(true));
+    ///
+    /// // Clearing the status bar leaves the main pane alone
+    /// display.viewport(&status_bar).clear(BinaryColor::Off).unwrap();
+    /// assert_eq!(display.get_pixel(0, 8), Some(true));
+    ///
+    /// // Only send the main pane to the display
+    /// display.flush_viewport(&main_pane).unwrap();
+    /// ```
+    #[cfg(feature = "graphics")]
+    pub fn viewport(&mut self, area: &Rectangle) -> Viewport<'_, SIZE, BUF> {
+        let rotation = self.rotation;
+
+        Viewport {
+            area: *area,
+            visible: area.intersection(&BufferedGraphicsMode::<SIZE, BUF>::bounding_box(rotation)),
+            mode: &mut self.mode,
+            rotation,
         }
     }
+
+    /// Copy an image in the display's native page format into the framebuffer with its top left
+    /// corner at (x, y).
+    ///
+    /// `data` holds the image as `height / 8` (rounded up) pages of `width` bytes each. Every byte
+    /// is a column of 8 vertical pixels with the top pixel in the least significant bit, the same
+    /// layout the display uses in memory. If `data` is too short, the image is cropped to the
+    /// whole pages it contains.
+    ///
+    /// When `y` is a multiple of 8 and the display is not rotated by 90 or 270 degrees, pages are
+    /// copied into the framebuffer directly. Parts of the image outside of the display are
+    /// clipped.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// // An 8x8 checkerboard
+    /// const CHECKERBOARD: [u8; 8] = [0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa, 0x55, 0xaa];
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.blit_page_image(4, 3, 8, 8, &CHECKERBOARD);
+    ///
+    /// assert_eq!(display.get_pixel(4, 3), Some(true));
+    /// assert_eq!(display.get_pixel(5, 3), Some(false));
+    /// assert_eq!(display.get_pixel(4, 4), Some(false));
+    /// ```
+    pub fn blit_page_image(&mut self, x: i32, y: i32, width: u32, height: u32, data: &[u8]) {
+        self.mode
+            .blit_page_image(self.rotation, x, y, width, height, data);
+    }
+
+    /// Get the state of a pixel in the framebuffer, taking into account the current rotation of
+    /// the display. Returns `None` if the X and Y coordinates are out of the bounds of the display.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_pixel(10, 100, true);
+    ///
+    /// assert_eq!(display.get_pixel(10, 100), Some(true));
+    /// assert_eq!(display.get_pixel(100, 10), None);
+    /// ```
+    pub fn get_pixel(&self, x: u32, y: u32) -> Option<bool> {
+        self.mode.get_pixel(self.rotation, x, y)
+    }
 }
 
 #[cfg(feature = "graphics")]
 use embedded_graphics_core::{
     draw_target::DrawTarget,
     geometry::Size,
-    geometry::{Dimensions, OriginDimensions},
+    geometry::{Dimensions, OriginDimensions, Point},
+    image::GetPixel,
     pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
     Pixel,
 };
 
@@ -244,6 +963,421 @@ use super::DisplayConfig;
 #[cfg(feature = "async")]
 use super::DisplayConfigAsync;
 
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async", idents(DisplaySize(async = "DisplaySizeAsync")))
+)]
+impl<SIZE, BUF> BufferedGraphicsMode<SIZE, BUF>
+where
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    /// Get the bounding box of the display for the given rotation.
+    fn bounding_box(rotation: DisplayRotation) -> Rectangle {
+        let (w, h) = Self::dimensions(rotation);
+
+        Rectangle::new(Point::zero(), Size::new(w.into(), h.into()))
+    }
+
+    /// Fill the part of `area` that lies within the display with `value`, combining it with the
+    /// framebuffer using `draw_mode`.
+    ///
+    /// Whole bytes are written where the area covers all 8 rows of a page, and masked bytes are
+    /// written at its top and bottom edges.
+    pub(crate) fn fill_rect(
+        &mut self,
+        rotation: DisplayRotation,
+        area: &Rectangle,
+        value: bool,
+        draw_mode: DrawMode,
+    ) {
+        let area = area.intersection(&Self::bounding_box(rotation));
+
+        let Some(bottom_right) = area.bottom_right() else {
+            return;
+        };
+
+        let (x, y) = (area.top_left.x as u8, area.top_left.y as u8);
+        let (width, height) = (area.size.width as u8, area.size.height as u8);
+
+        self.dirty.include(x, y);
+        self.dirty
+            .include(bottom_right.x as u8, bottom_right.y as u8);
+
+        // Each byte in the buffer covers 8 rows of a single column. For 90/270 degree rotations
+        // the columns of the buffer are the rows of the display.
+        let (col, row, cols, rows) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y, width, height),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (y, x, height, width),
+        };
+        let row = usize::from(row);
+
+        fill_pages(
+            self.buffer.as_mut(),
+            |col, page| Self::byte_index(rotation, col, page),
+            Self::byte_index(rotation, 1, 0),
+            (col.into(), cols.into()),
+            row..row + usize::from(rows),
+            value,
+            draw_mode,
+        );
+    }
+
+    /// Fill the part of `area` that lies within the display with `color`.
+    pub(crate) fn fill_solid(
+        &mut self,
+        rotation: DisplayRotation,
+        area: &Rectangle,
+        color: BinaryColor,
+    ) {
+        self.fill_rect(rotation, area, color.is_on(), self.draw_mode);
+    }
+
+    /// Invert the part of `area` that lies within the display, regardless of the draw mode.
+    pub(crate) fn invert_region(&mut self, rotation: DisplayRotation, area: &Rectangle) {
+        self.fill_rect(rotation, area, true, DrawMode::Xor);
+    }
+
+    /// Copy the part of `src` that lies within the display so that its top left corner ends up at
+    /// `dst`, regardless of the draw mode. Pixels that would be copied to outside of the display
+    /// are skipped. The regions may overlap.
+    pub(crate) fn copy_region(&mut self, rotation: DisplayRotation, src: &Rectangle, dst: Point) {
+        let bb = Self::bounding_box(rotation);
+        let offset = dst - src.top_left;
+
+        let src = src.intersection(&bb);
+        let dst = Rectangle::new(src.top_left + offset, src.size).intersection(&bb);
+
+        self.copy_rect(rotation, dst.top_left - offset, &dst);
+    }
+
+    /// Move the contents of the part of `area` that lies within the display by `dx` pixels to the
+    /// right and `dy` pixels down, regardless of the draw mode. Pixels moved out of `area` are
+    /// discarded and pixels moved into it are set to `fill`.
+    pub(crate) fn scroll_region(
+        &mut self,
+        rotation: DisplayRotation,
+        area: &Rectangle,
+        dx: i32,
+        dy: i32,
+        fill: BinaryColor,
+    ) {
+        let area = area.intersection(&Self::bounding_box(rotation));
+        let offset = Point::new(dx, dy);
+
+        let dst = Rectangle::new(area.top_left + offset, area.size).intersection(&area);
+        self.copy_rect(rotation, dst.top_left - offset, &dst);
+
+        // Fill the columns and rows that were uncovered
+        let (width, height) = (area.size.width as i32, area.size.height as i32);
+        let columns = Rectangle::new(
+            area.top_left + Point::new(if dx < 0 { width + dx } else { 0 }, 0),
+            Size::new(dx.unsigned_abs(), area.size.height),
+        );
+        let rows = Rectangle::new(
+            area.top_left + Point::new(0, if dy < 0 { height + dy } else { 0 }),
+            Size::new(area.size.width, dy.unsigned_abs()),
+        );
+
+        for uncovered in [columns, rows] {
+            let uncovered = uncovered.intersection(&area);
+            self.fill_rect(rotation, &uncovered, fill.is_on(), DrawMode::Replace);
+        }
+    }
+
+    /// Copy the pixels of the rectangle of size `dst.size` at `src` to `dst`. Both rectangles
+    /// must lie within the display.
+    fn copy_rect(&mut self, rotation: DisplayRotation, src: Point, dst: &Rectangle) {
+        let Some(bottom_right) = dst.bottom_right() else {
+            return;
+        };
+
+        self.dirty
+            .include(dst.top_left.x as u8, dst.top_left.y as u8);
+        self.dirty
+            .include(bottom_right.x as u8, bottom_right.y as u8);
+
+        // Each byte in the buffer covers 8 rows of a single column. For 90/270 degree rotations
+        // the columns of the buffer are the rows of the display.
+        let (src, dst, (cols, rows)) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
+                (src.x as usize, src.y as usize),
+                (dst.top_left.x as usize, dst.top_left.y as usize),
+                (dst.size.width as usize, dst.size.height as usize),
+            ),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
+                (src.y as usize, src.x as usize),
+                (dst.top_left.y as usize, dst.top_left.x as usize),
+                (dst.size.height as usize, dst.size.width as usize),
+            ),
+        };
+
+        let buffer = self.buffer.as_mut();
+        let stride = Self::byte_index(rotation, 1, 0);
+
+        // Copy in the opposite direction of the move, so that overlapping pixels are read before
+        // they are overwritten
+        let reverse = |src: usize, dst: usize, len: usize| {
+            let order = 0..len;
+            let rev = dst > src;
+            order.map(move |i| if rev { len - 1 - i } else { i })
+        };
+
+        if stride == 1 && src.1 % 8 == 0 && dst.1 % 8 == 0 && rows % 8 == 0 {
+            // Whole pages can be moved as runs of bytes
+            for page in reverse(src.1, dst.1, rows / 8) {
+                let start = Self::byte_index(rotation, src.0, src.1 / 8 + page);
+                let dest = Self::byte_index(rotation, dst.0, dst.1 / 8 + page);
+
+                buffer.copy_within(start..start + cols, dest);
+            }
+
+            return;
+        }
+
+        // Otherwise move one column at a time. A column is at most 64 pixels high, so all of its
+        // pages fit into a `u64`.
+        let pages = usize::from(SIZE::HEIGHT / 8);
+        let mask = u64::MAX >> (64 - rows);
+        let (first_page, last_page) = (dst.1 / 8, (dst.1 + rows - 1) / 8);
+
+        for col in reverse(src.0, dst.0, cols) {
+            let column = (0..pages).fold(0, |column, page| {
+                column
+                    | u64::from(buffer[Self::byte_index(rotation, src.0 + col, page)]) << (page * 8)
+            });
+            let bits = (column >> src.1 & mask) << dst.1;
+            let mask = mask << dst.1;
+
+            for page in first_page..=last_page {
+                let byte = &mut buffer[Self::byte_index(rotation, dst.0 + col, page)];
+                let (bits, mask) = ((bits >> (page * 8)) as u8, (mask >> (page * 8)) as u8);
+
+                *byte = DrawMode::Replace.apply(*byte, bits, mask);
+            }
+        }
+    }
+
+    /// Fill `area` with the colors from `colors`, in row-major order. Colors for pixels outside
+    /// of the display are skipped.
+    pub(crate) fn fill_contiguous(
+        &mut self,
+        rotation: DisplayRotation,
+        area: &Rectangle,
+        colors: impl IntoIterator<Item = BinaryColor>,
+    ) {
+        let drawable = area.intersection(&Self::bounding_box(rotation));
+
+        if drawable.is_zero_sized() {
+            return;
+        }
+
+        let mut colors = colors.into_iter();
+
+        // Number of colors outside of the display above the first row, and to the left and right
+        // of each row
+        let skip_top = (drawable.top_left.y - area.top_left.y) as usize * area.size.width as usize;
+        let skip_left = (drawable.top_left.x - area.top_left.x) as usize;
+        let skip_right = (area.size.width - drawable.size.width) as usize - skip_left;
+
+        if skip_top > 0 {
+            colors.nth(skip_top - 1);
+        }
+
+        for y in drawable.rows() {
+            if skip_left > 0 {
+                colors.nth(skip_left - 1);
+            }
+
+            self.draw_row(
+                rotation,
+                drawable.top_left.x as u8,
+                y as u8,
+                drawable.size.width as u8,
+                colors
+                    .by_ref()
+                    .take(drawable.size.width as usize)
+                    .map(BinaryColor::is_on),
+            );
+
+            if skip_right > 0 {
+                colors.nth(skip_right - 1);
+            }
+        }
+    }
+}
+
+/// A rectangular part of the framebuffer of a display in [`BufferedGraphicsMode`], with its own
+/// origin.
+///
+/// See [`Ssd1306::viewport`].
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+pub struct Viewport<'a, SIZE, BUF = <SIZE as DisplaySize>::Buffer>
+where
+    SIZE: DisplaySize,
+{
+    mode: &'a mut BufferedGraphicsMode<SIZE, BUF>,
+    rotation: DisplayRotation,
+    area: Rectangle,
+    /// The part of `area` within the display
+    visible: Rectangle,
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            Viewport(async = "ViewportAsync")
+        )
+    )
+)]
+impl<SIZE, BUF> core::fmt::Debug for Viewport<'_, SIZE, BUF>
+where
+    SIZE: DisplaySize,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Viewport")
+            .field("rotation", &self.rotation)
+            .field("area", &self.area)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            Viewport(async = "ViewportAsync")
+        )
+    )
+)]
+impl<SIZE, BUF> Viewport<'_, SIZE, BUF>
+where
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    /// Get the area of the display covered by this viewport, as passed to
+    /// [`Ssd1306::viewport`].
+    pub fn area(&self) -> Rectangle {
+        self.area
+    }
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            Viewport(async = "ViewportAsync")
+        )
+    )
+)]
+impl<SIZE, BUF> DrawTarget for Viewport<'_, SIZE, BUF>
+where
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (origin, visible) = (self.area.top_left, self.visible);
+
+        pixels
+            .into_iter()
+            .map(|Pixel(pos, color)| Pixel(pos + origin, color))
+            .filter(|Pixel(pos, _color)| visible.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.mode
+                    .set_pixel(self.rotation, pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        let translated = Rectangle::new(area.top_left + self.area.top_left, area.size);
+
+        // The framebuffer only clips to the display, so areas reaching outside of the viewport
+        // are drawn a pixel at a time
+        if self.visible.intersection(&translated) == translated {
+            self.mode
+                .fill_contiguous(self.rotation, &translated, colors);
+            Ok(())
+        } else {
+            self.draw_iter(
+                area.points()
+                    .zip(colors)
+                    .map(|(pos, color)| Pixel(pos, color)),
+            )
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = Rectangle::new(area.top_left + self.area.top_left, area.size)
+            .intersection(&self.visible);
+
+        self.mode.fill_solid(self.rotation, &area, color);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.fill_rect(
+            self.rotation,
+            &self.visible,
+            color.is_on(),
+            DrawMode::Replace,
+        );
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            Viewport(async = "ViewportAsync")
+        )
+    )
+)]
+impl<SIZE, BUF> OriginDimensions for Viewport<'_, SIZE, BUF>
+where
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    fn size(&self) -> Size {
+        self.area.size
+    }
+}
+
 #[cfg(feature = "graphics")]
 #[maybe_async_cfg::maybe(
     sync(keep_self),
@@ -256,10 +1390,11 @@ use super::DisplayConfigAsync;
         )
     )
 )]
-impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, BUF> DrawTarget for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
 {
     type Color = BinaryColor;
     type Error = DisplayError;
@@ -280,6 +1415,36 @@ where
         Ok(())
     }
 
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mode.fill_contiguous(self.rotation, area, colors);
+        Ok(())
+    }
+
+    /// Fill `area` with `color`, writing whole bytes of the framebuffer at once where possible.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// use embedded_graphics::{pixelcolor::BinaryColor, prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(StubInterface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode();
+    /// display.init().unwrap();
+    ///
+    /// let area = Rectangle::new(Point::new(3, 5), Size::new(20, 21));
+    /// display.fill_solid(&area, BinaryColor::On).unwrap();
+    ///
+    /// assert_eq!(display.get_pixel(3, 5), Some(true));
+    /// assert_eq!(display.get_pixel(3, 4), Some(false));
+    /// ```
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.fill_solid(self.rotation, area, color);
+        Ok(())
+    }
+
     fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
         self.clear_impl(color.is_on());
         Ok(())
@@ -298,10 +1463,11 @@ where
         )
     )
 )]
-impl<DI, SIZE> OriginDimensions for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, BUF> OriginDimensions for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
 {
     fn size(&self) -> Size {
         let (w, h) = self.dimensions();
@@ -309,3 +1475,31 @@ where
         Size::new(w.into(), h.into())
     }
 }
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self,),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+impl<DI, SIZE, BUF> GetPixel for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, BUF>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+    BUF: AsMut<[u8]> + AsRef<[u8]>,
+{
+    type Color = BinaryColor;
+
+    fn pixel(&self, p: Point) -> Option<BinaryColor> {
+        let x = u32::try_from(p.x).ok()?;
+        let y = u32::try_from(p.y).ok()?;
+
+        self.get_pixel(x, y).map(BinaryColor::from)
+    }
+}
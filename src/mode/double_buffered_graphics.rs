@@ -0,0 +1,527 @@
+//! Double buffered graphics mode.
+
+use core::{fmt, future::Future};
+
+use crate::{
+    mode::{BufferedGraphicsModeAsync, DirtyArea, DisplayConfigAsync},
+    rotation::DisplayRotation,
+    size::{DisplaySizeAsync, NewZeroed},
+    Ssd1306Async,
+};
+use display_interface::{AsyncWriteOnlyDataCommand, DisplayError};
+
+/// Double buffered graphics mode.
+///
+/// This mode works like [`BufferedGraphicsModeAsync`], but keeps a front buffer and a back buffer
+/// in system memory, up to 2048 bytes for 128x64px displays. Drawing always goes to the back
+/// buffer. [`present`](Ssd1306Async::present) swaps the two buffers and sends the new front buffer
+/// to the display.
+///
+/// Use [`begin_present`](Ssd1306Async::begin_present) to draw the next frame into the back buffer
+/// while the previous frame is still being transferred.
+///
+/// After a swap the back buffer holds the same frame as the front buffer, so the next frame can be
+/// drawn on top of it, or from scratch after clearing it. To get there, the parts of the frame
+/// that were drawn to since the previous swap are copied into the back buffer, so a swap costs as
+/// much as copying the area that changed. Only that area is sent to the display.
+///
+/// If sending a frame fails, or the future returned by `begin_present` is dropped before it
+/// completes, the area that was not sent is sent again by the next present.
+#[derive(Clone, Debug)]
+pub struct DoubleBufferedGraphicsModeAsync<SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    front: SIZE::Buffer,
+    back: BufferedGraphicsModeAsync<SIZE>,
+    /// The area of the front buffer that was not sent to the display yet
+    unsent: DirtyArea,
+}
+
+impl<SIZE> DoubleBufferedGraphicsModeAsync<SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    /// Create a new double buffered graphics mode instance.
+    pub(crate) fn new() -> Self {
+        Self {
+            front: NewZeroed::new_zeroed(),
+            back: BufferedGraphicsModeAsync::new(),
+            unsent: DirtyArea::empty(),
+        }
+    }
+
+    /// Swap the buffers and return the area of the new front buffer that must be sent.
+    fn swap(&mut self, rotation: DisplayRotation) -> DirtyArea {
+        core::mem::swap(&mut self.front, &mut self.back.buffer);
+        let drawn = core::mem::replace(&mut self.back.dirty, DirtyArea::empty());
+
+        // The new back buffer holds the frame before the new front buffer, which only differs from
+        // it where the new frame was drawn to
+        if !drawn.is_empty() {
+            let (cols, pages) = match rotation {
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                    (drawn.min_x..=drawn.max_x, drawn.min_y / 8..=drawn.max_y / 8)
+                }
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                    (drawn.min_y..=drawn.max_y, drawn.min_x / 8..=drawn.max_x / 8)
+                }
+            };
+
+            let (front, back) = (self.front.as_ref(), self.back.buffer.as_mut());
+            for page in pages {
+                for col in cols.clone() {
+                    let idx = BufferedGraphicsModeAsync::<SIZE>::byte_index(
+                        rotation,
+                        col.into(),
+                        page.into(),
+                    );
+                    back[idx] = front[idx];
+                }
+            }
+        }
+
+        // Parts of the previous frame that were not sent may still be out of date on the display
+        drawn.union(core::mem::replace(&mut self.unsent, DirtyArea::empty()))
+    }
+}
+
+impl<DI, SIZE> DisplayConfigAsync for Ssd1306Async<DI, SIZE, DoubleBufferedGraphicsModeAsync<SIZE>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    type Error = DisplayError;
+
+    /// Set the display rotation
+    ///
+    /// This method does not clear the buffers, but remaps them to the new rotation.
+    async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+        self.set_rotation(rot).await
+    }
+
+    /// Initialise and clear the display and both buffers in graphics mode.
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        self.mode.front.as_mut().fill(0);
+        self.mode.back.clear(self.rotation, false);
+        self.mode.unsent = DirtyArea::empty();
+        self.init_with_addr_mode(Self::graphics_addr_mode(self.rotation))
+            .await
+    }
+}
+
+impl<DI, SIZE> Ssd1306Async<DI, SIZE, DoubleBufferedGraphicsModeAsync<SIZE>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    /// Set the display rotation.
+    ///
+    /// Both buffers are remapped to the layout of the new rotation, so the next
+    /// [`present`](Self::present) shows the same image in the new orientation. When switching
+    /// between landscape and portrait, pixels that fall outside of the rotated display are
+    /// discarded.
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        BufferedGraphicsModeAsync::<SIZE>::remap_layout(
+            self.mode.front.as_mut(),
+            self.rotation,
+            rotation,
+        );
+        self.mode.back.rotate(self.rotation, rotation);
+        // The whole back buffer is sent by the next present
+        self.mode.unsent = DirtyArea::empty();
+
+        self.send_rotation(rotation).await
+    }
+
+    /// Clear the back buffer. You need to call `disp.present()` for any effect on the screen.
+    pub fn clear_buffer(&mut self) {
+        self.mode.back.clear(self.rotation, false);
+    }
+
+    /// Turn a pixel in the back buffer on or off. If the X and Y coordinates are out of the bounds
+    /// of the display, this method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        self.mode.back.set_pixel(self.rotation, x, y, value);
+    }
+
+    /// Swap the front and back buffers and write the new front buffer out to the display.
+    ///
+    /// This only updates the parts of the display that have changed since the last call.
+    pub async fn present(&mut self) -> Result<(), DisplayError> {
+        let (_back, transfer) = self.begin_present();
+
+        transfer.await
+    }
+
+    /// Swap the front and back buffers, returning the new back buffer and a future that writes
+    /// the new front buffer out to the display.
+    ///
+    /// The back buffer can be drawn to while the transfer is in progress, for example by joining
+    /// the returned future with the rendering code using `embassy_futures::join`:
+    ///
+    /// ```rust,ignore
+    /// let (mut back, transfer) = display.begin_present();
+    ///
+    /// let (result, _) = join(transfer, async {
+    ///     back.clear_buffer();
+    ///     draw_next_frame(&mut back);
+    /// })
+    /// .await;
+    /// result?;
+    /// ```
+    ///
+    /// Each present shows the back buffer as it was drawn, including everything drawn in earlier
+    /// frames:
+    ///
+    /// ```rust
+    /// use embassy_futures::block_on;
+    /// use ssd1306::{prelude::*, test_helpers::SimulatedInterface, Ssd1306Async};
+    ///
+    /// let mut display = Ssd1306Async::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_double_buffered_graphics_mode();
+    ///
+    /// block_on(async {
+    ///     display.init().await.unwrap();
+    ///
+    ///     // Draw one pixel per frame
+    ///     for (x, y) in [(1, 1), (40, 20), (100, 60), (20, 50)] {
+    ///         display.set_pixel(x, y, true);
+    ///         display.present().await.unwrap();
+    ///     }
+    ///
+    ///     // Draw the next frame while the previous one is sent, and turn a pixel off again
+    ///     let (mut back, transfer) = display.begin_present();
+    ///     back.set_pixel(1, 5, true);
+    ///     back.set_pixel(70, 30, true);
+    ///     back.set_pixel(40, 20, false);
+    ///     transfer.await.unwrap();
+    ///     display.present().await.unwrap();
+    /// });
+    ///
+    /// let interface = display.release();
+    /// assert!(interface.pixel::<DisplaySize128x64>(1, 1));
+    /// assert!(interface.pixel::<DisplaySize128x64>(1, 5));
+    /// assert!(!interface.pixel::<DisplaySize128x64>(40, 20));
+    /// assert!(interface.pixel::<DisplaySize128x64>(100, 60));
+    /// assert!(interface.pixel::<DisplaySize128x64>(20, 50));
+    /// assert!(interface.pixel::<DisplaySize128x64>(70, 30));
+    /// ```
+    pub fn begin_present(
+        &mut self,
+    ) -> (
+        BackBuffer<'_, SIZE>,
+        impl Future<Output = Result<(), DisplayError>> + '_,
+    ) {
+        let area = self.mode.swap(self.rotation);
+        let offset_x = self.column_offset();
+
+        let back = BackBuffer {
+            mode: &mut self.mode.back,
+            rotation: self.rotation,
+        };
+
+        // Until the transfer completes, the area is sent again by the next present
+        let unsent = &mut self.mode.unsent;
+        *unsent = area;
+
+        let transfer = Self::flush_area(
+            &mut self.interface,
+            &mut self.addr_mode,
+            self.rotation,
+            offset_x,
+            self.mode.front.as_mut(),
+            area,
+        );
+        let transfer = async move {
+            transfer.await?;
+            *unsent = DirtyArea::empty();
+            Ok(())
+        };
+
+        (back, transfer)
+    }
+}
+
+/// The back buffer of a display in [`DoubleBufferedGraphicsModeAsync`], borrowed while the front
+/// buffer is being sent to the display.
+///
+/// See [`Ssd1306Async::begin_present`].
+pub struct BackBuffer<'a, SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    mode: &'a mut BufferedGraphicsModeAsync<SIZE>,
+    rotation: DisplayRotation,
+}
+
+impl<SIZE> fmt::Debug for BackBuffer<'_, SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BackBuffer")
+            .field("rotation", &self.rotation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<SIZE> BackBuffer<'_, SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    /// Clear the back buffer.
+    pub fn clear_buffer(&mut self) {
+        self.mode.clear(self.rotation, false);
+    }
+
+    /// Turn a pixel in the back buffer on or off. If the X and Y coordinates are out of the bounds
+    /// of the display, this method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        self.mode.set_pixel(self.rotation, x, y, value);
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    geometry::{Dimensions, OriginDimensions},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> DrawTarget for Ssd1306Async<DI, SIZE, DoubleBufferedGraphicsModeAsync<SIZE>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mode.back.fill_contiguous(self.rotation, area, colors);
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.back.fill_solid(self.rotation, area, color);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.back.clear(self.rotation, color.is_on());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<DI, SIZE> OriginDimensions for Ssd1306Async<DI, SIZE, DoubleBufferedGraphicsModeAsync<SIZE>>
+where
+    DI: AsyncWriteOnlyDataCommand,
+    SIZE: DisplaySizeAsync,
+{
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(w.into(), h.into())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<SIZE> DrawTarget for BackBuffer<'_, SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mode.fill_contiguous(self.rotation, area, colors);
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.fill_solid(self.rotation, area, color);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.clear(self.rotation, color.is_on());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+impl<SIZE> OriginDimensions for BackBuffer<'_, SIZE>
+where
+    SIZE: DisplaySizeAsync,
+{
+    fn size(&self) -> Size {
+        let (w, h) = BufferedGraphicsModeAsync::<SIZE>::dimensions(self.rotation);
+
+        Size::new(w.into(), h.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mode::DisplayConfig, size::DisplaySize128x64, test_helpers::SimulatedInterface, Ssd1306,
+    };
+    use display_interface::{DataFormat, WriteOnlyDataCommand};
+    use embassy_futures::block_on;
+
+    /// A simulated display whose data transfers can be made to fail.
+    #[derive(Debug, Default)]
+    struct FlakyInterface {
+        panel: SimulatedInterface,
+        fail: bool,
+    }
+
+    impl AsyncWriteOnlyDataCommand for FlakyInterface {
+        async fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+            WriteOnlyDataCommand::send_commands(&mut self.panel, cmd)
+        }
+
+        async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+            if self.fail {
+                return Err(DisplayError::BusWriteError);
+            }
+            WriteOnlyDataCommand::send_data(&mut self.panel, buf)
+        }
+    }
+
+    type Display<DI> =
+        Ssd1306Async<DI, DisplaySize128x64, DoubleBufferedGraphicsModeAsync<DisplaySize128x64>>;
+
+    fn display<DI: AsyncWriteOnlyDataCommand>(
+        interface: DI,
+        rotation: DisplayRotation,
+    ) -> Display<DI> {
+        let mut display = Ssd1306Async::new(interface, DisplaySize128x64, rotation)
+            .into_double_buffered_graphics_mode();
+        block_on(display.init()).unwrap();
+        display
+    }
+
+    #[test]
+    fn frames_build_on_each_other_in_every_rotation() {
+        for rotation in [
+            DisplayRotation::Rotate0,
+            DisplayRotation::Rotate90,
+            DisplayRotation::Rotate180,
+            DisplayRotation::Rotate270,
+        ] {
+            let mut display = display(SimulatedInterface::new(), rotation);
+            let mut reference =
+                Ssd1306::new(SimulatedInterface::new(), DisplaySize128x64, rotation)
+                    .into_buffered_graphics_mode();
+            DisplayConfig::init(&mut reference).unwrap();
+
+            // Pixels sharing a page with earlier ones, in other pages, and turned off again
+            for (x, y, value) in [
+                (1, 1, true),
+                (40, 20, true),
+                (1, 5, true),
+                (60, 50, true),
+                (40, 20, false),
+                (2, 1, true),
+                (1, 1, false),
+            ] {
+                display.set_pixel(x, y, value);
+                reference.set_pixel(x, y, value);
+                block_on(display.present()).unwrap();
+                reference.flush().unwrap();
+
+                let (panel, expected) = (&display.interface, &reference.interface);
+                for y in 0..64 {
+                    for x in 0..128 {
+                        assert_eq!(
+                            panel.pixel::<DisplaySize128x64>(x, y),
+                            expected.pixel::<DisplaySize128x64>(x, y),
+                            "{rotation:?}, pixel ({x}, {y})",
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn failed_transfer_is_sent_again() {
+        let mut display = display(FlakyInterface::default(), DisplayRotation::Rotate0);
+        block_on(display.present()).unwrap();
+
+        display.set_pixel(10, 10, true);
+        display.interface.fail = true;
+        assert!(block_on(display.present()).is_err());
+        display.interface.fail = false;
+
+        // A later frame that does not touch the failed area still updates it
+        display.set_pixel(100, 50, true);
+        block_on(display.present()).unwrap();
+
+        assert!(display.interface.panel.pixel::<DisplaySize128x64>(10, 10));
+        assert!(display.interface.panel.pixel::<DisplaySize128x64>(100, 50));
+    }
+
+    #[test]
+    fn dropped_transfer_is_sent_again() {
+        let mut display = display(SimulatedInterface::new(), DisplayRotation::Rotate0);
+        block_on(display.present()).unwrap();
+
+        display.set_pixel(10, 10, true);
+        let (_back, transfer) = display.begin_present();
+        drop(transfer);
+
+        block_on(display.present()).unwrap();
+
+        assert!(display.interface.pixel::<DisplaySize128x64>(10, 10));
+    }
+}
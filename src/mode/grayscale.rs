@@ -0,0 +1,429 @@
+//! Grayscale mode.
+
+use core::fmt;
+
+#[cfg(feature = "async")]
+use crate::{mode::BufferedGraphicsModeAsync, size::DisplaySizeAsync, Ssd1306Async};
+use crate::{
+    mode::{BufferedGraphicsMode, DirtyArea},
+    rotation::DisplayRotation,
+    size::DisplaySize,
+    Ssd1306,
+};
+#[cfg(feature = "async")]
+use display_interface::AsyncWriteOnlyDataCommand;
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+
+use super::DisplayConfig;
+#[cfg(feature = "async")]
+use super::DisplayConfigAsync;
+
+/// The planes shown by successive calls to `tick`, as indices into `GrayscaleMode::planes`. The
+/// high plane is shown twice as long as the low plane.
+const PLANE_SEQUENCE: [usize; 3] = [1, 1, 0];
+
+/// Grayscale mode.
+///
+/// The SSD1306 can only turn pixels on or off, but switching between several images faster than
+/// the eye can follow makes pixels that are only on in some of them appear dimmer. This mode
+/// keeps two bitplanes, up to 2048 bytes for 128x64px displays, holding the low and high bit of a
+/// 2 bit gray level for each pixel. Each call to [`tick`](Ssd1306::tick) shows the next step of
+/// a cycle of 3 in which the high plane is shown for two steps and the low plane for one, so
+/// pixels at level 0, 1, 2 and 3 are on for 0, 1, 2 and 3 steps respectively.
+///
+/// `tick` must be called at a steady rate for the levels to look even. A full cycle sends both
+/// planes to the display once, i.e. `2 * WIDTH * HEIGHT / 8` bytes, plus any parts of the shown
+/// plane that were drawn to in the meantime. To avoid visible flicker a cycle should take no
+/// longer than about 1/30 s, so a 128x64px display needs around 60 KiB/s of bus bandwidth. This
+/// is more than a 400 kHz I2C bus can provide; use SPI or I2C at 1 MHz or more.
+///
+/// Results also depend on the refresh rate of the panel, which can be tuned with
+/// [`Command::DisplayClockDiv`](crate::command::Command::DisplayClockDiv).
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+pub struct GrayscaleMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// The low and high bit of each pixel's gray level
+    planes: [BufferedGraphicsMode<SIZE>; 2],
+    /// Position in `PLANE_SEQUENCE` of the next plane to show
+    step: usize,
+    /// The plane currently shown on the display, if any
+    shown: Option<usize>,
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            GrayscaleMode(async = "GrayscaleModeAsync")
+        )
+    )
+)]
+impl<SIZE> fmt::Debug for GrayscaleMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GrayscaleMode")
+            .field("step", &self.step)
+            .field("shown", &self.shown)
+            .finish_non_exhaustive()
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+impl<SIZE> GrayscaleMode<SIZE>
+where
+    SIZE: DisplaySize,
+{
+    /// Create a new grayscale mode instance.
+    pub(crate) fn new() -> Self {
+        Self {
+            planes: [BufferedGraphicsMode::new(), BufferedGraphicsMode::new()],
+            step: 0,
+            shown: None,
+        }
+    }
+
+    /// Set both planes to the given gray level.
+    fn clear(&mut self, rotation: DisplayRotation, level: u8) {
+        for (bit, plane) in self.planes.iter_mut().enumerate() {
+            plane.clear(rotation, level & (1 << bit) != 0);
+        }
+    }
+
+    /// Set the gray level of a pixel.
+    fn set_pixel(&mut self, rotation: DisplayRotation, x: u32, y: u32, level: u8) {
+        for (bit, plane) in self.planes.iter_mut().enumerate() {
+            plane.set_pixel(rotation, x, y, level & (1 << bit) != 0);
+        }
+    }
+
+    /// Advance to the next step of the cycle, returning the plane to show and the area of it
+    /// that must be sent to the display.
+    fn next_plane(&mut self, rotation: DisplayRotation) -> (&[u8], DirtyArea) {
+        let index = PLANE_SEQUENCE[self.step];
+        self.step = (self.step + 1) % PLANE_SEQUENCE.len();
+
+        let plane = &mut self.planes[index];
+        let changed = core::mem::replace(&mut plane.dirty, DirtyArea::empty());
+
+        // Only the parts drawn to since the plane was sent need to be sent again if it is still
+        // on the display
+        let area = if self.shown == Some(index) {
+            changed
+        } else {
+            let (width, height) = BufferedGraphicsMode::<SIZE>::dimensions(rotation);
+            DirtyArea::full(width, height)
+        };
+        self.shown = Some(index);
+
+        (plane.buffer.as_ref(), area)
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            DisplayConfig(async = "DisplayConfigAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            GrayscaleMode(async = "GrayscaleModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Error = DisplayError;
+
+    /// Set the display rotation
+    ///
+    /// Both planes are remapped to the new rotation.
+    async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+        self.set_rotation(rot).await
+    }
+
+    /// Initialise the display in graphics mode and clear both planes.
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        self.mode.clear(self.rotation, 0);
+        self.mode.shown = None;
+        self.init_with_addr_mode(Self::graphics_addr_mode(self.rotation))
+            .await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            GrayscaleMode(async = "GrayscaleModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE> Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Set the display rotation.
+    ///
+    /// Both planes are remapped to the layout of the new rotation, so the next
+    /// [`tick`](Self::tick) shows the same image in the new orientation. When switching between
+    /// landscape and portrait, pixels that fall outside of the rotated display are discarded.
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        for plane in &mut self.mode.planes {
+            plane.rotate(self.rotation, rotation);
+        }
+        self.mode.shown = None;
+
+        self.send_rotation(rotation).await
+    }
+
+    /// Set every pixel of both planes to black.
+    pub fn clear_buffer(&mut self) {
+        self.mode.clear(self.rotation, 0);
+    }
+
+    /// Set the gray level of a pixel, from 0 (off) to 3 (fully on). Only the lowest 2 bits of
+    /// `level` are used. If the X and Y coordinates are out of the bounds of the display, this
+    /// method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, level: u8) {
+        self.mode.set_pixel(self.rotation, x, y, level);
+    }
+
+    /// Show the next plane of the grayscale cycle. Call this at a steady rate, e.g. from a timer.
+    ///
+    /// The plane is only sent to the display when it differs from the plane shown before, so
+    /// calls alternate between sending a whole plane and sending only what was drawn since.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::SimulatedInterface;
+    /// # let interface = SimulatedInterface::new();
+    /// use embedded_graphics::{pixelcolor::Gray2, prelude::*, primitives::Rectangle};
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_grayscale_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Four bars from black to white
+    /// for level in 0..4 {
+    ///     display
+    ///         .fill_solid(
+    ///             &Rectangle::new(Point::new(level * 32, 0), Size::new(32, 64)),
+    ///             Gray2::new(level as u8),
+    ///         )
+    ///         .unwrap();
+    /// }
+    ///
+    /// # let mut frames = 0;
+    /// loop {
+    ///     display.tick().unwrap();
+    ///     // Wait for the next tick
+    ///     # frames += 1;
+    ///     # if frames == 6 { break; }
+    /// }
+    /// # // The last step of the cycle shows the low plane
+    /// # let panel = display.release();
+    /// # let shown = [16, 48, 80, 112].map(|x| panel.pixel::<DisplaySize128x64>(x, 0));
+    /// # assert_eq!(shown, [false, true, false, true]);
+    /// ```
+    pub async fn tick(&mut self) -> Result<(), DisplayError> {
+        let offset_x = self.column_offset();
+        let (plane, area) = self.mode.next_plane(self.rotation);
+
+        Self::flush_area(
+            &mut self.interface,
+            &mut self.addr_mode,
+            self.rotation,
+            offset_x,
+            plane,
+            area,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    geometry::{Dimensions, OriginDimensions},
+    pixelcolor::{Gray2, GrayColor},
+    primitives::Rectangle,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+use super::DrawMode;
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            GrayscaleMode(async = "GrayscaleModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = Gray2;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.luma());
+            });
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        for (bit, plane) in self.mode.planes.iter_mut().enumerate() {
+            let value = color.luma() & (1 << bit) != 0;
+            plane.fill_rect(self.rotation, area, value, DrawMode::Replace);
+        }
+
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.clear(self.rotation, color.luma());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            GrayscaleMode(async = "GrayscaleModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE> OriginDimensions for Ssd1306<DI, SIZE, GrayscaleMode<SIZE>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(w.into(), h.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{size::DisplaySize128x64, test_helpers::SimulatedInterface};
+
+    #[test]
+    fn tick_shows_planes_in_sequence() {
+        let mut display = Ssd1306::new(
+            SimulatedInterface::new(),
+            DisplaySize128x64,
+            DisplayRotation::Rotate0,
+        )
+        .into_grayscale_mode();
+        display.init().unwrap();
+
+        // One pixel of each gray level
+        for level in 0..4 {
+            display.set_pixel(level.into(), 0, level);
+        }
+
+        // The high plane is shown for two steps and the low plane for one
+        let high = [false, false, true, true];
+        let low = [false, true, false, true];
+        let mut on_steps = [0; 4];
+
+        for expected in [high, high, low, high, high, low] {
+            display.tick().unwrap();
+
+            let shown = [0, 1, 2, 3].map(|x| display.interface.pixel::<DisplaySize128x64>(x, 0));
+            assert_eq!(shown, expected);
+
+            for (steps, on) in on_steps.iter_mut().zip(shown) {
+                *steps += u8::from(on);
+            }
+        }
+
+        // Each level is on for as many steps of a cycle as its value
+        assert_eq!(on_steps, [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn tick_only_sends_changes_to_the_shown_plane() {
+        let mut display = Ssd1306::new(
+            SimulatedInterface::new(),
+            DisplaySize128x64,
+            DisplayRotation::Rotate0,
+        )
+        .into_grayscale_mode();
+        display.init().unwrap();
+        display.interface.take_written_pages();
+
+        // Switching to the high plane sends all of it
+        display.tick().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0xff);
+
+        // Showing it again only sends what was drawn in the meantime
+        display.set_pixel(5, 20, 3);
+        display.tick().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0b0000_0100);
+        assert!(display.interface.pixel::<DisplaySize128x64>(5, 20));
+
+        // Switching to the low plane sends all of it
+        display.tick().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0xff);
+    }
+}
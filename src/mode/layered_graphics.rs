@@ -0,0 +1,659 @@
+//! Layered graphics mode.
+
+use core::fmt;
+
+#[cfg(feature = "async")]
+use crate::{mode::BufferedGraphicsModeAsync, size::DisplaySizeAsync, Ssd1306Async};
+use crate::{
+    mode::{BufferedGraphicsMode, DirtyArea, DrawMode},
+    rotation::DisplayRotation,
+    size::DisplaySize,
+    Ssd1306,
+};
+#[cfg(feature = "async")]
+use display_interface::AsyncWriteOnlyDataCommand;
+use display_interface::{DisplayError, WriteOnlyDataCommand};
+
+use super::DisplayConfig;
+#[cfg(feature = "async")]
+use super::DisplayConfigAsync;
+
+/// An image that can be placed over the background of a display in [`LayeredGraphicsMode`].
+///
+/// The image is stored in the display's native page format, as used by
+/// [`blit_page_image`](Ssd1306::blit_page_image): `height / 8` (rounded up) pages of `width`
+/// bytes each, with the top pixel of each column of 8 in the least significant bit.
+///
+/// Without a mask, pixels that are off in the image are transparent. With a mask in the same
+/// format, pixels that are off in the mask are transparent and all others are drawn in the color
+/// of the image, so a sprite can turn pixels of the background off as well. Pixels of the image
+/// outside of the mask must be off.
+#[derive(Clone, Copy, Debug)]
+pub struct Sprite {
+    image: &'static [u8],
+    mask: Option<&'static [u8]>,
+    width: u32,
+    height: u32,
+}
+
+impl Sprite {
+    /// Create a new sprite from an image of `width` x `height` pixels.
+    pub const fn new(width: u32, height: u32, image: &'static [u8]) -> Self {
+        Self {
+            image,
+            mask: None,
+            width,
+            height,
+        }
+    }
+
+    /// Use `mask` to select the opaque pixels of the sprite.
+    pub const fn with_mask(self, mask: &'static [u8]) -> Self {
+        Self {
+            mask: Some(mask),
+            ..self
+        }
+    }
+
+    /// Get the size of the sprite, as (width, height).
+    pub const fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+/// A sprite and the position of its top left corner.
+#[derive(Clone, Copy, Debug)]
+struct PlacedSprite {
+    sprite: Sprite,
+    x: i32,
+    y: i32,
+}
+
+impl PlacedSprite {
+    /// Grow `area` to include the part of the sprite that lies within the display.
+    fn include_in(&self, area: &mut DirtyArea, (width, height): (u8, u8)) {
+        let (x, y) = (i64::from(self.x), i64::from(self.y));
+        let x_end = (x + i64::from(self.sprite.width)).min(width.into());
+        let y_end = (y + i64::from(self.sprite.height)).min(height.into());
+
+        if x.max(0) < x_end && y.max(0) < y_end {
+            area.include(x.max(0) as u8, y.max(0) as u8);
+            area.include((x_end - 1) as u8, (y_end - 1) as u8);
+        }
+    }
+
+    /// Whether the sprite covers any pixel of `area`.
+    fn overlaps(&self, area: &DirtyArea) -> bool {
+        let (x, y) = (i64::from(self.x), i64::from(self.y));
+
+        x <= area.max_x.into()
+            && y <= area.max_y.into()
+            && x + i64::from(self.sprite.width) > area.min_x.into()
+            && y + i64::from(self.sprite.height) > area.min_y.into()
+    }
+}
+
+/// Layered graphics mode.
+///
+/// This mode keeps a background framebuffer that is drawn to like the framebuffer of
+/// [`BufferedGraphicsMode`], and up to `N` [`Sprite`]s that are placed over it with
+/// [`set_sprite`](Ssd1306::set_sprite) and moved around with
+/// [`move_sprite`](Ssd1306::move_sprite). Sprites with a higher index are drawn over those with a
+/// lower index.
+///
+/// The background and sprites are composited into a second framebuffer when the display is
+/// flushed, so this mode uses twice as much memory as [`BufferedGraphicsMode`]. Only the parts of
+/// the display where the background changed or sprites were moved are composited again and sent
+/// to the display, so a static background does not need to be redrawn every frame.
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+pub struct LayeredGraphicsMode<SIZE, const N: usize>
+where
+    SIZE: DisplaySize,
+{
+    background: BufferedGraphicsMode<SIZE>,
+    output: BufferedGraphicsMode<SIZE>,
+    sprites: [Option<PlacedSprite>; N],
+    /// The area covered by sprites that were placed, moved or removed since the last flush
+    moved: DirtyArea,
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync")
+        )
+    )
+)]
+impl<SIZE, const N: usize> fmt::Debug for LayeredGraphicsMode<SIZE, N>
+where
+    SIZE: DisplaySize,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LayeredGraphicsMode")
+            .field("sprites", &self.sprites)
+            .field("moved", &self.moved)
+            .finish_non_exhaustive()
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            BufferedGraphicsMode(async = "BufferedGraphicsModeAsync")
+        )
+    )
+)]
+impl<SIZE, const N: usize> LayeredGraphicsMode<SIZE, N>
+where
+    SIZE: DisplaySize,
+{
+    /// Create a new layered graphics mode instance.
+    pub(crate) fn new() -> Self {
+        Self {
+            background: BufferedGraphicsMode::new(),
+            output: BufferedGraphicsMode::new(),
+            sprites: [None; N],
+            moved: DirtyArea::empty(),
+        }
+    }
+
+    /// Mark the area covered by the sprite at `index`, if any, as changed.
+    fn mark_sprite(&mut self, rotation: DisplayRotation, index: usize) {
+        if let Some(placed) = self.sprites[index] {
+            placed.include_in(
+                &mut self.moved,
+                BufferedGraphicsMode::<SIZE>::dimensions(rotation),
+            );
+        }
+    }
+
+    /// Composite the background and sprites into the output buffer wherever anything changed,
+    /// returning the area of the output buffer that must be sent to the display.
+    fn composite(&mut self, rotation: DisplayRotation) -> DirtyArea {
+        let (width, height) = BufferedGraphicsMode::<SIZE>::dimensions(rotation);
+
+        // Sprites moved before a change of rotation may have marked pixels outside of the display
+        let mut area = core::mem::replace(&mut self.background.dirty, DirtyArea::empty())
+            .union(core::mem::replace(&mut self.moved, DirtyArea::empty()))
+            .intersection((0, 0), (width - 1, height - 1));
+
+        if area.is_empty() {
+            return area;
+        }
+
+        // The background is copied a byte at a time, and sprites that overlap the area are drawn
+        // in full, so grow the area to whole pages and to every sprite it touches
+        loop {
+            match rotation {
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                    area.min_y &= !7;
+                    area.max_y |= 7;
+                }
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                    area.min_x &= !7;
+                    area.max_x |= 7;
+                }
+            }
+
+            let mut grown = area;
+            for placed in self.sprites.iter().flatten() {
+                if placed.overlaps(&area) {
+                    placed.include_in(&mut grown, (width, height));
+                }
+            }
+
+            if grown == area {
+                break;
+            }
+            area = grown;
+        }
+
+        // Column and page ranges of the area in display memory
+        let (cols, pages) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                (area.min_x..=area.max_x, area.min_y / 8..=area.max_y / 8)
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (area.min_y..=area.max_y, area.min_x / 8..=area.max_x / 8)
+            }
+        };
+
+        let background = self.background.buffer.as_ref();
+        let output = self.output.buffer.as_mut();
+
+        for page in pages {
+            for col in cols.clone() {
+                let idx =
+                    BufferedGraphicsMode::<SIZE>::byte_index(rotation, col.into(), page.into());
+                output[idx] = background[idx];
+            }
+        }
+
+        for placed in self.sprites.iter().flatten() {
+            if !placed.overlaps(&area) {
+                continue;
+            }
+
+            let PlacedSprite { sprite, x, y } = *placed;
+
+            if let Some(mask) = sprite.mask {
+                self.output.draw_mode = DrawMode::AndNot;
+                self.output
+                    .blit_page_image(rotation, x, y, sprite.width, sprite.height, mask);
+            }
+
+            self.output.draw_mode = DrawMode::Or;
+            self.output
+                .blit_page_image(rotation, x, y, sprite.width, sprite.height, sprite.image);
+        }
+
+        // Everything drawn above lies within the area
+        self.output.dirty = DirtyArea::empty();
+
+        area
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            DisplayConfig(async = "DisplayConfigAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> DisplayConfig for Ssd1306<DI, SIZE, LayeredGraphicsMode<SIZE, N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Error = DisplayError;
+
+    /// Set the display rotation
+    ///
+    /// The background is remapped to the new rotation. Sprites keep their positions.
+    async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), DisplayError> {
+        self.set_rotation(rot).await
+    }
+
+    /// Initialise the display in graphics mode and clear the background.
+    async fn init(&mut self) -> Result<(), DisplayError> {
+        self.mode.background.clear(self.rotation, false);
+        self.init_with_addr_mode(Self::graphics_addr_mode(self.rotation))
+            .await
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> Ssd1306<DI, SIZE, LayeredGraphicsMode<SIZE, N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Set the display rotation.
+    ///
+    /// The background is remapped to the layout of the new rotation and the whole display is
+    /// composited again on the next [`flush`](Self::flush). Sprites keep their positions in the
+    /// rotated display.
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        self.mode.background.rotate(self.rotation, rotation);
+        self.send_rotation(rotation).await
+    }
+
+    /// Clear the background. You need to call `disp.flush()` for any effect on the screen.
+    pub fn clear_buffer(&mut self) {
+        self.mode.background.clear(self.rotation, false);
+    }
+
+    /// Turn a pixel of the background on or off. If the X and Y coordinates are out of the bounds
+    /// of the display, this method call is a noop.
+    pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
+        self.mode.background.set_pixel(self.rotation, x, y, value);
+    }
+
+    /// Place `sprite` with its top left corner at (x, y), replacing the sprite at `index`, if
+    /// any. Parts of the sprite outside of the display are clipped.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::Sprite, prelude::*, Ssd1306};
+    ///
+    /// // A solid 8x8 block
+    /// const BLOCK: Sprite = Sprite::new(8, 8, &[0xff; 8]);
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_layered_graphics_mode::<2>();
+    /// display.init().unwrap();
+    ///
+    /// // Draw the static parts of the display once
+    /// display.set_pixel(0, 0, true);
+    /// display.flush().unwrap();
+    ///
+    /// display.set_sprite(0, BLOCK, 10, 20);
+    /// for x in 11..100 {
+    ///     // Only the area around the block is composited and sent
+    ///     display.move_sprite(0, x, 20);
+    ///     display.flush().unwrap();
+    /// }
+    ///
+    /// assert_eq!(display.sprite_position(0), Some((99, 20)));
+    /// assert_eq!(display.sprite_position(1), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn set_sprite(&mut self, index: usize, sprite: Sprite, x: i32, y: i32) {
+        self.mode.mark_sprite(self.rotation, index);
+        self.mode.sprites[index] = Some(PlacedSprite { sprite, x, y });
+        self.mode.mark_sprite(self.rotation, index);
+    }
+
+    /// Move the sprite at `index` so that its top left corner is at (x, y). If there is no sprite
+    /// at `index`, this method call is a noop.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn move_sprite(&mut self, index: usize, x: i32, y: i32) {
+        if let Some(placed) = self.mode.sprites[index] {
+            self.set_sprite(index, placed.sprite, x, y);
+        }
+    }
+
+    /// Remove the sprite at `index`, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn remove_sprite(&mut self, index: usize) {
+        self.mode.mark_sprite(self.rotation, index);
+        self.mode.sprites[index] = None;
+    }
+
+    /// Get the position of the sprite at `index`, or `None` if there is no sprite at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not smaller than `N`.
+    pub fn sprite_position(&self, index: usize) -> Option<(i32, i32)> {
+        self.mode.sprites[index].map(|placed| (placed.x, placed.y))
+    }
+
+    /// Composite the background and sprites and write them out to the display.
+    ///
+    /// This only updates the parts of the display where the background has changed or sprites
+    /// were placed, moved or removed since the last flush.
+    pub async fn flush(&mut self) -> Result<(), DisplayError> {
+        let area = self.mode.composite(self.rotation);
+        let offset_x = self.column_offset();
+
+        Self::flush_area(
+            &mut self.interface,
+            &mut self.addr_mode,
+            self.rotation,
+            offset_x,
+            self.mode.output.buffer.as_mut(),
+            area,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::Size,
+    geometry::{Dimensions, OriginDimensions},
+    pixelcolor::BinaryColor,
+    primitives::Rectangle,
+    Pixel,
+};
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> DrawTarget for Ssd1306<DI, SIZE, LayeredGraphicsMode<SIZE, N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    type Color = BinaryColor;
+    type Error = DisplayError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bb = self.bounding_box();
+
+        pixels
+            .into_iter()
+            .filter(|Pixel(pos, _color)| bb.contains(*pos))
+            .for_each(|Pixel(pos, color)| {
+                self.set_pixel(pos.x as u32, pos.y as u32, color.is_on());
+            });
+
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.mode
+            .background
+            .fill_contiguous(self.rotation, area, colors);
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.background.fill_solid(self.rotation, area, color);
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.mode.background.clear(self.rotation, color.is_on());
+        Ok(())
+    }
+}
+
+#[cfg(feature = "graphics")]
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+            LayeredGraphicsMode(async = "LayeredGraphicsModeAsync"),
+        )
+    )
+)]
+impl<DI, SIZE, const N: usize> OriginDimensions for Ssd1306<DI, SIZE, LayeredGraphicsMode<SIZE, N>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    fn size(&self) -> Size {
+        let (w, h) = self.dimensions();
+
+        Size::new(w.into(), h.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{size::DisplaySize128x64, test_helpers::SimulatedInterface};
+
+    type Display<const N: usize> =
+        Ssd1306<SimulatedInterface, DisplaySize128x64, LayeredGraphicsMode<DisplaySize128x64, N>>;
+
+    /// A solid 8x8 block, and one that turns the background off.
+    const WHITE: Sprite = Sprite::new(8, 8, &[0xff; 8]).with_mask(&[0xff; 8]);
+    const BLACK: Sprite = Sprite::new(8, 8, &[0x00; 8]).with_mask(&[0xff; 8]);
+
+    fn background(x: u8, y: u8) -> bool {
+        (x + y) % 3 == 0
+    }
+
+    /// A display with the background pattern on the panel.
+    fn display<const N: usize>() -> Display<N> {
+        let mut display = Ssd1306::new(
+            SimulatedInterface::new(),
+            DisplaySize128x64,
+            DisplayRotation::Rotate0,
+        )
+        .into_layered_graphics_mode::<N>();
+        display.init().unwrap();
+
+        for y in 0..64 {
+            for x in 0..128 {
+                display.set_pixel(x.into(), y.into(), background(x, y));
+            }
+        }
+        display.flush().unwrap();
+        display.interface.take_written_pages();
+
+        display
+    }
+
+    fn pixel<const N: usize>(display: &Display<N>, x: u8, y: u8) -> bool {
+        display.interface.pixel::<DisplaySize128x64>(x, y)
+    }
+
+    #[test]
+    fn mask_clears_background_and_image_is_drawn_on_top() {
+        // Rows 0 and 2 on, rows 1 and 3 off, rows 4 to 7 transparent
+        const MASKED: Sprite = Sprite::new(8, 8, &[0b0101; 8]).with_mask(&[0b1111; 8]);
+        // Rows 0 and 2 on, all others transparent
+        const UNMASKED: Sprite = Sprite::new(8, 8, &[0b0101; 8]);
+
+        let mut display = display::<2>();
+        display.set_sprite(0, MASKED, 10, 16);
+        display.set_sprite(1, UNMASKED, 30, 16);
+        display.flush().unwrap();
+
+        for (left, masked) in [(10, true), (30, false)] {
+            for x in left..left + 8 {
+                for y in 16..24 {
+                    let row = y - 16;
+                    let expected = match row {
+                        0 | 2 => true,
+                        1 | 3 if masked => false,
+                        _ => background(x, y),
+                    };
+                    assert_eq!(pixel(&display, x, y), expected, "pixel ({x}, {y})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overlapping_sprites_are_drawn_in_index_order() {
+        let mut display = display::<2>();
+        display.set_sprite(0, BLACK, 10, 8);
+        display.set_sprite(1, WHITE, 14, 8);
+        display.flush().unwrap();
+
+        assert!(!pixel(&display, 12, 10));
+        assert!(pixel(&display, 15, 10));
+        assert!(pixel(&display, 20, 10));
+
+        display.set_sprite(0, WHITE, 10, 8);
+        display.set_sprite(1, BLACK, 14, 8);
+        display.flush().unwrap();
+
+        assert!(pixel(&display, 12, 10));
+        assert!(!pixel(&display, 15, 10));
+        assert!(!pixel(&display, 20, 10));
+    }
+
+    #[test]
+    fn move_and_remove_restore_background() {
+        let mut display = display::<1>();
+        display.set_sprite(0, WHITE, 10, 20);
+        display.flush().unwrap();
+        display.move_sprite(0, 40, 21);
+        display.flush().unwrap();
+
+        for y in 0..64 {
+            for x in 0..128 {
+                let on_sprite = (40..48).contains(&x) && (21..29).contains(&y);
+                let expected = on_sprite || background(x, y);
+                assert_eq!(pixel(&display, x, y), expected, "pixel ({x}, {y})");
+            }
+        }
+
+        display.remove_sprite(0);
+        display.flush().unwrap();
+
+        for y in 0..64 {
+            for x in 0..128 {
+                assert_eq!(pixel(&display, x, y), background(x, y), "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn flush_sends_only_affected_pages() {
+        let mut display = display::<1>();
+
+        // Rows 20 to 27 are in pages 2 and 3
+        display.set_sprite(0, WHITE, 10, 20);
+        display.flush().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0b0000_1100);
+
+        // Moving it into page 4 also clears it from page 2
+        display.move_sprite(0, 11, 25);
+        display.flush().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0b0001_1100);
+
+        display.set_pixel(0, 60, true);
+        display.flush().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0b1000_0000);
+
+        display.flush().unwrap();
+        assert_eq!(display.interface.take_written_pages(), 0);
+    }
+}
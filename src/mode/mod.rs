@@ -1,12 +1,30 @@
 //! Display modes.
 
+mod banded_graphics;
 mod buffered_graphics;
+#[cfg(feature = "async")]
+mod double_buffered_graphics;
+mod grayscale;
+mod layered_graphics;
 mod terminal;
+mod terminal_font;
+#[cfg(all(test, feature = "graphics"))]
+mod tests;
 
 use crate::{command::AddrMode, rotation::DisplayRotation, size::DisplaySize, Ssd1306};
+#[cfg(feature = "async")]
+use crate::{size::DisplaySizeAsync, Ssd1306Async};
+pub use banded_graphics::*;
 pub use buffered_graphics::*;
+#[cfg(feature = "async")]
+use display_interface::AsyncWriteOnlyDataCommand;
 use display_interface::{DisplayError, WriteOnlyDataCommand};
+#[cfg(feature = "async")]
+pub use double_buffered_graphics::*;
+pub use grayscale::*;
+pub use layered_graphics::*;
 pub use terminal::*;
+pub use terminal_font::*;
 
 /// Common functions to all display modes.
 #[maybe_async_cfg::maybe(sync(keep_self,), async(feature = "async"))]
@@ -61,6 +79,27 @@ where
     }
 }
 
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(
+        feature = "async",
+        idents(
+            DisplaySize(async = "DisplaySizeAsync"),
+            WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
+        )
+    )
+)]
+impl<DI, SIZE> Ssd1306<DI, SIZE, BasicMode>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: DisplaySize,
+{
+    /// Set the display rotation.
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        self.send_rotation(rotation).await
+    }
+}
+
 impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, BasicMode>
 where
     DI: WriteOnlyDataCommand,
@@ -1,9 +1,15 @@
 #[cfg(feature = "async")]
-use crate::mode::DisplayConfigAsync;
-use crate::{command::AddrMode, mode::DisplayConfig, rotation::DisplayRotation, size::*, Ssd1306};
+use crate::{command::CommandAsync, mode::DisplayConfigAsync};
+use crate::{
+    command::{AddrMode, Command},
+    mode::{CustomGlyph, DisplayConfig, Font6x8, TerminalFont},
+    rotation::DisplayRotation,
+    size::*,
+    Ssd1306,
+};
 #[cfg(feature = "async")]
 use crate::{size::DisplaySizeAsync, Ssd1306Async};
-use core::{cmp::min, fmt};
+use core::{cmp::min, fmt, marker::PhantomData};
 #[cfg(feature = "async")]
 use display_interface::AsyncWriteOnlyDataCommand;
 use display_interface::{DisplayError, WriteOnlyDataCommand};
@@ -21,7 +27,11 @@ use display_interface::{DisplayError, WriteOnlyDataCommand};
 /// Extends the [`DisplaySize`](crate::size::DisplaySize) trait
 /// to include number of characters that can fit on the display.
 pub trait TerminalDisplaySize: DisplaySize {
-    /// The number of characters that can fit on the display at once (w * h / (8 * 8))
+    /// The number of 8x8 pixel cells that fit on the display (w * h / (8 * 8))
+    ///
+    /// This is not the number of characters shown by [`TerminalMode`], which depends on its font
+    /// and cell width. Use [`Ssd1306::char_dimensions`] instead.
+    #[deprecated(note = "the number of characters depends on the font, use `char_dimensions`")]
     const CHAR_NUM: u8;
 }
 
@@ -85,6 +95,21 @@ impl TerminalDisplaySize for DisplaySize64x48 {
     const CHAR_NUM: u8 = 48;
 }
 
+/// Number of pages in the display RAM, which the visible rows are a window onto
+const RAM_PAGES: u8 = 8;
+
+/// The distance between tab stops unless configured otherwise, in characters
+const DEFAULT_TAB_WIDTH: u8 = 4;
+
+/// The widest character cell supported, in pixels
+const MAX_CELL_WIDTH: u8 = 16;
+
+/// The highest character cell supported, in pages
+const MAX_CELL_PAGES: u8 = 4;
+
+/// The number of bytes needed for the largest character cell
+const MAX_CELL_BYTES: usize = MAX_CELL_WIDTH as usize * MAX_CELL_PAGES as usize;
+
 /// Contains the new row that the cursor has wrapped around to
 struct CursorWrapEvent(u8);
 
@@ -97,9 +122,7 @@ struct Cursor {
 }
 
 impl Cursor {
-    pub fn new(width_pixels: u8, height_pixels: u8) -> Self {
-        let width = width_pixels / 8;
-        let height = height_pixels / 8;
+    pub fn new(width: u8, height: u8) -> Self {
         Cursor {
             col: 0,
             row: 0,
@@ -129,6 +152,38 @@ impl Cursor {
         CursorWrapEvent(self.row)
     }
 
+    /// Moves the logical cursor back by one character, to the end of the previous line if it is
+    /// at the start of a line.
+    /// Returns `false` if the cursor is already in the top left corner.
+    pub fn retreat(&mut self) -> bool {
+        match (self.col, self.row) {
+            (0, 0) => false,
+            (0, row) => {
+                self.col = self.width - 1;
+                self.row = row - 1;
+                true
+            }
+            (col, _) => {
+                self.col = col - 1;
+                true
+            }
+        }
+    }
+
+    /// Advances the logical cursor to the next tab stop, placed every `tab_width` characters.
+    /// Returns a value indicating if there is no tab stop left on the line, so the cursor wrapped
+    /// to the start of the next line.
+    pub fn advance_tab(&mut self, tab_width: u8) -> Option<CursorWrapEvent> {
+        let next = (u16::from(self.col) / u16::from(tab_width) + 1) * u16::from(tab_width);
+        match u8::try_from(next) {
+            Ok(col) if col < self.width => {
+                self.col = col;
+                None
+            }
+            _ => Some(self.advance_line()),
+        }
+    }
+
     /// Sets the position of the logical cursor arbitrarily.
     /// The position will be capped at the maximal possible position.
     pub fn set_position(&mut self, col: u8, row: u8) {
@@ -147,8 +202,127 @@ impl Cursor {
     }
 }
 
+/// The number of bytes of formatted text the async `write_fmt` can print at once
+#[cfg(feature = "async")]
+const FORMAT_BUFFER: usize = 128;
+
+/// Maximum number of numeric parameters kept for a control sequence
+const MAX_PARAMS: usize = 4;
+
+/// State of the parser for escape sequences
+#[derive(Copy, Clone, Debug, Default)]
+enum EscapeState {
+    /// Printing characters
+    #[default]
+    Ground,
+    /// Received `ESC`
+    Escape,
+    /// Inside a control sequence, i.e. after `ESC [`
+    Csi {
+        params: [u16; MAX_PARAMS],
+        /// Index of the parameter currently being received
+        current: usize,
+        /// Whether the sequence uses features that are not supported and should be ignored
+        ignore: bool,
+    },
+}
+
+/// Attributes that change how [`TerminalMode`] draws characters.
+///
+/// The attributes are applied to each character cell when it is drawn, so changing them does not
+/// affect characters that are already on the display.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TextAttributes {
+    /// Invert the pixels of the cell
+    pub inverse: bool,
+    /// Turn on the bottom row of pixels of the cell
+    pub underline: bool,
+    /// Draw the glyph again one pixel to the right to make it look bold
+    pub bold: bool,
+}
+
+/// How the cursor of [`TerminalMode`] is shown, see
+/// [`set_cursor_style`](Ssd1306::set_cursor_style).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CursorStyle {
+    /// A line along the bottom of the character cell
+    Underline,
+    /// The whole character cell
+    Block,
+}
+
+/// A character cell stored in the text buffer of [`TerminalMode`], see
+/// [`with_text_buffer`](Ssd1306::with_text_buffer).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextCell {
+    /// The character shown in the cell
+    pub c: char,
+    /// The attributes the character is drawn with
+    pub attributes: TextAttributes,
+}
+
+impl TextCell {
+    /// An empty cell, as left by clearing the display.
+    pub const EMPTY: Self = Self {
+        c: ' ',
+        attributes: TextAttributes {
+            inverse: false,
+            underline: false,
+            bold: false,
+        },
+    };
+}
+
+impl Default for TextCell {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// The number of [`TextCell`]s needed to store all characters of a display of size `SIZE` in
+/// [`TerminalMode`] with font `FONT`, in any rotation and cell width.
+///
+/// ```rust
+/// use ssd1306::{
+///     mode::{text_buffer_len, Font6x8},
+///     size::DisplaySize128x64,
+/// };
+///
+/// assert_eq!(text_buffer_len::<DisplaySize128x64, Font6x8>(), 168);
+/// ```
+pub const fn text_buffer_len<SIZE: DisplaySize, FONT: TerminalFont>() -> usize {
+    // Cells are narrowest when the cell width is set to the glyph width
+    let width = if FONT::WIDTH < MAX_CELL_WIDTH {
+        FONT::WIDTH
+    } else {
+        MAX_CELL_WIDTH
+    };
+    let pages = if FONT::PAGES < MAX_CELL_PAGES {
+        FONT::PAGES
+    } else {
+        MAX_CELL_PAGES
+    };
+    if width == 0 || pages == 0 {
+        return 0;
+    }
+
+    let (display_width, display_pages) = (SIZE::WIDTH as usize, SIZE::HEIGHT as usize / 8);
+    let landscape = (display_width / width as usize) * (display_pages / pages as usize);
+    let portrait =
+        (display_width / (pages as usize * 8)) * (display_pages / width.div_ceil(8) as usize);
+
+    if landscape > portrait {
+        landscape
+    } else {
+        portrait
+    }
+}
+
 /// Errors which can occur when interacting with the terminal mode
+///
+/// More variants may be added in the future, so matches need a wildcard arm.
 #[derive(Clone)]
+#[non_exhaustive]
 pub enum TerminalModeError {
     /// An error occurred in the underlying interface layer
     InterfaceError(DisplayError),
@@ -156,6 +330,12 @@ pub enum TerminalModeError {
     Uninitialized,
     /// A location was specified outside the bounds of the screen
     OutOfBounds,
+    /// The operation needs a text buffer, see [`with_text_buffer`](Ssd1306::with_text_buffer)
+    NoTextBuffer,
+    /// Formatting text failed, or the formatted text was too long to be printed at once
+    FormatError,
+    /// The operation is not supported in the current rotation
+    Unsupported,
 }
 
 impl fmt::Debug for TerminalModeError {
@@ -164,6 +344,9 @@ impl fmt::Debug for TerminalModeError {
             Self::InterfaceError(_) => "InterfaceError".fmt(f),
             Self::Uninitialized => "Uninitialized".fmt(f),
             Self::OutOfBounds => "OutOfBound".fmt(f),
+            Self::NoTextBuffer => "NoTextBuffer".fmt(f),
+            Self::FormatError => "FormatError".fmt(f),
+            Self::Unsupported => "Unsupported".fmt(f),
         }
     }
 }
@@ -175,23 +358,150 @@ impl From<DisplayError> for TerminalModeError {
 }
 
 /// Terminal mode.
+///
+/// Text is drawn using the font `FONT`, which defaults to [`Font6x8`]. Use
+/// [`into_terminal_mode_with_font`](Ssd1306::into_terminal_mode_with_font) to select another
+/// font.
+///
+/// By default the cursor wraps around to the top of the display after the last row. Use
+/// [`set_scroll_on_overflow`](Ssd1306::set_scroll_on_overflow) to scroll the text up instead.
+///
+/// The cursor is hidden by default. Use [`set_cursor_style`](Ssd1306::set_cursor_style) to show
+/// it, which needs a text buffer, and [`tick`](Ssd1306::tick) to make it blink.
+///
+/// The text on the display is only kept in the display RAM unless a text buffer is provided with
+/// [`with_text_buffer`](Ssd1306::with_text_buffer). The buffer `BUF` is a slice of [`TextCell`]s
+/// and allows to [`redraw`](Ssd1306::redraw) the text and read it back with
+/// [`char_at`](Ssd1306::char_at).
+///
+/// The following control characters are supported:
+///
+/// - `'\n'` - move the cursor to the start of the next line
+/// - `'\r'` - move the cursor to the start of the current line
+/// - `'\t'` - move the cursor to the next tab stop, see
+///   [`set_tab_width`](Ssd1306::set_tab_width)
+/// - `'\x08'` (backspace) - move the cursor back by one character and clear that character
+/// - `'\x0c'` (form feed) - clear the display and move the cursor to the top left corner
+///
+/// The following subset of VT100 escape sequences is supported, where `CSI` is `ESC [` and
+/// rows and columns start at 1:
+///
+/// - `CSI row ; col H` and `CSI row ; col f` - move the cursor to the given position
+/// - `CSI n A`, `CSI n B`, `CSI n C` and `CSI n D` - move the cursor up, down, right or left by
+///   `n` characters
+/// - `CSI n J` - clear from the cursor to the end of the screen (`n` = 0), from the start of the
+///   screen to the cursor (1) or the whole screen (2)
+/// - `CSI n K` - clear from the cursor to the end of the line (`n` = 0), from the start of the
+///   line to the cursor (1) or the whole line (2)
+/// - `CSI n m` - set the [`TextAttributes`] used to draw characters: bold (`n` = 1), underline (4)
+///   and inverse video (7), or turn them off again (22, 24 and 27). `CSI 0 m` or `CSI m` turns all
+///   attributes off. Several attributes can be separated by `;`.
+///
+/// Other escape sequences are ignored.
 #[maybe_async_cfg::maybe(
     sync(keep_self),
     async(feature = "async", idents(TerminalMode(async = "TerminalModeAsync")))
 )]
-#[derive(Debug, Copy, Clone, Default)]
-pub struct TerminalMode {
+#[derive(Debug, Clone)]
+pub struct TerminalMode<FONT = Font6x8, BUF = [TextCell; 0]> {
     cursor: Option<Cursor>,
+    /// Scroll up instead of wrapping around to the top row
+    scroll: bool,
+    /// The display RAM page shown as the top row
+    top_page: u8,
+    escape: EscapeState,
+    /// Attributes used to draw characters
+    attributes: TextAttributes,
+    /// Overrides the cell width of the font
+    cell_width: Option<u8>,
+    /// Glyphs that are used instead of the glyphs of the font
+    custom_glyphs: &'static [CustomGlyph],
+    /// The distance between tab stops, in characters
+    tab_width: u8,
+    /// How the cursor is shown, if at all
+    cursor_style: Option<CursorStyle>,
+    /// Whether a blinking cursor is currently in its visible phase
+    cursor_on: bool,
+    /// The number of ticks between toggling the cursor
+    blink_ticks: u8,
+    /// The number of ticks since the cursor was last toggled
+    ticks: u8,
+    /// The character cell the cursor is drawn in
+    cursor_drawn: Option<(u8, u8)>,
+    /// The characters on the display, row after row
+    text: BUF,
+    /// The number of (columns, rows) the text buffer is laid out for
+    text_dimensions: (u8, u8),
+    /// The error that made the last write through `fmt::Write` fail
+    write_error: Option<TerminalModeError>,
+    font: PhantomData<FONT>,
 }
 
 #[maybe_async_cfg::maybe(
     sync(keep_self),
     async(feature = "async", idents(TerminalMode(async = "TerminalModeAsync")))
 )]
-impl TerminalMode {
+impl<FONT> Default for TerminalMode<FONT> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async", idents(TerminalMode(async = "TerminalModeAsync")))
+)]
+impl<FONT> TerminalMode<FONT> {
     /// Create a new terminal mode config instance.
     pub fn new() -> Self {
-        Self::default()
+        Self {
+            cursor: None,
+            scroll: false,
+            top_page: 0,
+            escape: EscapeState::Ground,
+            attributes: TextAttributes::default(),
+            cell_width: None,
+            custom_glyphs: &[],
+            tab_width: DEFAULT_TAB_WIDTH,
+            cursor_style: None,
+            cursor_on: true,
+            blink_ticks: 1,
+            ticks: 0,
+            cursor_drawn: None,
+            text: [],
+            text_dimensions: (0, 0),
+            write_error: None,
+            font: PhantomData,
+        }
+    }
+}
+
+#[maybe_async_cfg::maybe(
+    sync(keep_self),
+    async(feature = "async", idents(TerminalMode(async = "TerminalModeAsync")))
+)]
+impl<FONT, BUF> TerminalMode<FONT, BUF> {
+    /// Use `text` as the text buffer, keeping all other settings.
+    fn with_text_buffer<BUF2>(&self, text: BUF2) -> TerminalMode<FONT, BUF2> {
+        TerminalMode {
+            cursor: self.cursor,
+            scroll: self.scroll,
+            top_page: self.top_page,
+            escape: self.escape,
+            attributes: self.attributes,
+            cell_width: self.cell_width,
+            custom_glyphs: self.custom_glyphs,
+            tab_width: self.tab_width,
+            cursor_style: self.cursor_style,
+            cursor_on: self.cursor_on,
+            blink_ticks: self.blink_ticks,
+            ticks: self.ticks,
+            cursor_drawn: self.cursor_drawn,
+            text,
+            text_dimensions: self.text_dimensions,
+            write_error: self.write_error.clone(),
+            font: PhantomData,
+        }
     }
 }
 
@@ -208,10 +518,12 @@ impl TerminalMode {
         )
     )
 )]
-impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, FONT, BUF> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode<FONT, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    FONT: TerminalFont,
+    BUF: AsMut<[TextCell]> + AsRef<[TextCell]>,
 {
     type Error = TerminalModeError;
 
@@ -229,6 +541,7 @@ where
     /// wrapping.
     async fn init(&mut self) -> Result<(), TerminalModeError> {
         self.init_with_addr_mode(AddrMode::Page).await?;
+        self.mode.cursor_drawn = None;
         self.reset_pos().await
     }
 }
@@ -243,27 +556,501 @@ where
             WriteOnlyDataCommand(async = "AsyncWriteOnlyDataCommand"),
             TerminalMode(async = "TerminalModeAsync"),
             TerminalDisplaySize(async = "TerminalDisplaySizeAsync"),
+            Command(async = "CommandAsync"),
         )
     )
 )]
-impl<DI, SIZE> Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, FONT, BUF> Ssd1306<DI, SIZE, TerminalMode<FONT, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    FONT: TerminalFont,
+    BUF: AsMut<[TextCell]> + AsRef<[TextCell]>,
 {
+    /// Set the display rotation.
+    ///
+    /// This method neither clears the screen nor resets the cursor. Use
+    /// [`DisplayConfig::set_rotation`] to also reset the cursor, and [`redraw`](Self::redraw) to
+    /// show the text in the new rotation. Text that was scrolled up moves back to where it was
+    /// written in the display RAM.
+    ///
+    /// Rotating the display by 90 or 270 degrees turns off
+    /// [`set_scroll_on_overflow`](Self::set_scroll_on_overflow).
+    pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
+        // The text on the display is not redrawn in the new rotation, and neither is the cursor
+        self.mode.cursor_drawn = None;
+
+        if self.mode.top_page != 0 {
+            self.mode.top_page = 0;
+            Command::StartLine(0).send(&mut self.interface).await?;
+        }
+        if matches!(
+            rotation,
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270
+        ) {
+            self.mode.scroll = false;
+        }
+
+        self.send_rotation(rotation).await
+    }
+
+    /// Scroll the text up by one row when the cursor moves past the last row, instead of wrapping
+    /// around to the top row.
+    ///
+    /// Scrolling uses the display start line, so the text on the display does not need to be
+    /// redrawn. The cursor position stays relative to the top of the display.
+    ///
+    /// Scrolling is only supported for [`DisplayRotation::Rotate0`] and
+    /// [`DisplayRotation::Rotate180`]. When the display is rotated by 90 or 270 degrees, rows of
+    /// text are columns of the display, which cannot be scrolled this way, so enabling scrolling
+    /// returns [`TerminalModeError::Unsupported`].
+    ///
+    /// Resetting the cursor, e.g. with [`DisplayConfig::set_rotation`] or
+    /// [`set_cell_width`](Self::set_cell_width), also undoes the scrolling. The text is then drawn
+    /// again from the text buffer, see [`with_text_buffer`](Self::with_text_buffer), or the
+    /// display is cleared if there is none.
+    ///
+    /// ```rust
+    /// use ssd1306::{prelude::*, test_helpers::SimulatedInterface, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x32,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    /// display.set_scroll_on_overflow(true).unwrap();
+    ///
+    /// for line in '0'..='9' {
+    ///     for c in ['\n', 'l', 'i', 'n', 'e', ' ', line] {
+    ///         display.print_char(c).unwrap();
+    ///     }
+    /// }
+    ///
+    /// // The last line is written to the bottom row
+    /// assert_eq!(display.position().unwrap(), (6, 3));
+    ///
+    /// // The display shows the last four lines
+    /// let mut expected = Ssd1306::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x32,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_terminal_mode();
+    /// expected.init().unwrap();
+    /// expected.clear().unwrap();
+    /// for c in "line 6\nline 7\nline 8\nline 9".chars() {
+    ///     expected.print_char(c).unwrap();
+    /// }
+    ///
+    /// let (display, expected) = (display.release(), expected.release());
+    /// for y in 0..32 {
+    ///     for x in 0..128 {
+    ///         assert_eq!(
+    ///             display.pixel::<DisplaySize128x32>(x, y),
+    ///             expected.pixel::<DisplaySize128x32>(x, y),
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn set_scroll_on_overflow(&mut self, scroll: bool) -> Result<(), TerminalModeError> {
+        if scroll
+            && matches!(
+                self.rotation,
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270
+            )
+        {
+            return Err(TerminalModeError::Unsupported);
+        }
+
+        self.mode.scroll = scroll;
+        Ok(())
+    }
+
+    /// Store the text on the display in `buffer`, which allows to [`redraw`](Self::redraw) it and
+    /// to read it back with [`char_at`](Self::char_at). Characters are not sent to the display
+    /// again if the same character with the same attributes is printed over them.
+    ///
+    /// The buffer holds one [`TextCell`] per character cell, row after row. Use
+    /// [`text_buffer_len`] to get a length that fits all characters. Characters that do not fit
+    /// into a shorter buffer are shown on the display but not stored.
+    ///
+    /// The buffer is cleared, so this should be called before any text is printed. The buffer is
+    /// assumed to match the display, so [`clear`](Self::clear) the display after
+    /// [`init`](DisplayConfig::init), or call [`redraw`](Self::redraw) after the display was reset.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{
+    ///     mode::{text_buffer_len, Font6x8, TextCell},
+    ///     prelude::*,
+    ///     Ssd1306,
+    /// };
+    ///
+    /// const LEN: usize = text_buffer_len::<DisplaySize128x64, Font6x8>();
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode()
+    ///     .with_text_buffer([TextCell::EMPTY; LEN]);
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// for c in "Hello".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    /// assert_eq!(display.char_at(1, 0), Some('e'));
+    ///
+    /// // Show the text again after rotating the display
+    /// DisplayConfig::set_rotation(&mut display, DisplayRotation::Rotate90).unwrap();
+    /// display.redraw().unwrap();
+    /// assert_eq!(display.char_at(1, 0), Some('e'));
+    /// ```
+    pub fn with_text_buffer<BUF2>(self, buffer: BUF2) -> Ssd1306<DI, SIZE, TerminalMode<FONT, BUF2>>
+    where
+        BUF2: AsMut<[TextCell]> + AsRef<[TextCell]>,
+    {
+        let mut mode = self.mode.with_text_buffer(buffer);
+        mode.text.as_mut().fill(TextCell::EMPTY);
+        mode.text_dimensions = mode.cursor.as_ref().map_or((0, 0), Cursor::get_dimensions);
+
+        self.into_mode(mode)
+    }
+
+    /// Draw the text stored in the text buffer on the display again, for example after the display
+    /// was rotated or initialized again. The cursor does not move.
+    ///
+    /// Without a text buffer, see [`with_text_buffer`](Self::with_text_buffer), this clears the
+    /// display.
+    pub async fn redraw(&mut self) -> Result<(), TerminalModeError> {
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        self.blank_display().await?;
+
+        for row in 0..height {
+            for column in 0..width {
+                match self.text_cell(column, row) {
+                    Some(cell) if cell != TextCell::EMPTY => {
+                        let rendered = self.render_char(cell.c, cell.attributes);
+                        self.draw_cell(column, row, &rendered).await?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.refresh_cursor().await
+    }
+
+    /// Get the character at `column`, `row` from the text buffer.
+    ///
+    /// Returns `None` if there is no text buffer, see
+    /// [`with_text_buffer`](Self::with_text_buffer), or if the position is outside the display or
+    /// the buffer.
+    pub fn char_at(&self, column: u8, row: u8) -> Option<char> {
+        self.text_cell(column, row).map(|cell| cell.c)
+    }
+
+    /// Set the width of a character cell in pixels, instead of the
+    /// [`CELL_WIDTH`](TerminalFont::CELL_WIDTH) of the font.
+    ///
+    /// Narrower cells fit more columns on the display. The width must be at least the glyph
+    /// width of the font and at most 16 pixels, otherwise [`TerminalModeError::OutOfBounds`] is
+    /// returned. When the display is rotated by 90 or 270 degrees, the width is rounded up to a
+    /// multiple of 8 pixels.
+    ///
+    /// This method resets the cursor but does not clear the screen.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    /// display.init().unwrap();
+    ///
+    /// // The 6 pixel wide glyphs of the default font without any space around them
+    /// display.set_cell_width(6).unwrap();
+    /// display.clear().unwrap();
+    ///
+    /// assert_eq!(display.char_dimensions(), (21, 8));
+    /// ```
+    pub async fn set_cell_width(&mut self, width: u8) -> Result<(), TerminalModeError> {
+        if width < FONT::WIDTH || width > MAX_CELL_WIDTH {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        self.erase_cursor().await?;
+        self.mode.cell_width = Some(width);
+
+        // The number of columns may have changed, so the cursor position can become invalid
+        if self.mode.cursor.is_some() {
+            self.reset_pos().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Set the distance between tab stops, in characters. The default is 4.
+    ///
+    /// A tab (`'\t'`) moves the cursor to the next tab stop, or to the start of the next line if
+    /// there is no tab stop left on the current line. A width of 0 returns
+    /// [`TerminalModeError::OutOfBounds`].
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.set_tab_width(8).unwrap();
+    ///
+    /// for c in "ab\tc".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    ///
+    /// assert_eq!(display.position().unwrap(), (9, 0));
+    /// ```
+    pub fn set_tab_width(&mut self, width: u8) -> Result<(), TerminalModeError> {
+        if width == 0 {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        self.mode.tab_width = width;
+        Ok(())
+    }
+
+    /// Clear a row of text. The cursor does not move.
+    ///
+    /// If the row is out of bounds, an Err will be returned.
+    pub async fn clear_line(&mut self, row: u8) -> Result<(), TerminalModeError> {
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        if row >= height {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        self.clear_cells(row, 0, width).await?;
+        self.refresh_cursor().await
+    }
+
+    /// Clear the row of the cursor, from the cursor to the end of the row. The cursor does not
+    /// move.
+    pub async fn clear_to_end_of_line(&mut self) -> Result<(), TerminalModeError> {
+        let (width, _) = self.ensure_cursor()?.get_dimensions();
+        let (column, row) = self.position()?;
+
+        self.clear_cells(row, column, width).await?;
+        self.refresh_cursor().await
+    }
+
+    /// Show the cursor at the position the next character will be written to, or hide it with
+    /// `None`. The cursor is hidden by default.
+    ///
+    /// The cursor is drawn over the character in its cell, which is drawn again when the cursor
+    /// moves on or blinks. The display RAM cannot be read back, so showing the cursor needs a text
+    /// buffer, see [`with_text_buffer`](Self::with_text_buffer). Without one,
+    /// [`TerminalModeError::NoTextBuffer`] is returned. The cursor is not shown in cells that do
+    /// not fit into the text buffer.
+    ///
+    /// ```rust
+    /// use ssd1306::{
+    ///     mode::{text_buffer_len, CursorStyle, Font6x8, TextCell},
+    ///     prelude::*,
+    ///     test_helpers::SimulatedInterface,
+    ///     Ssd1306,
+    /// };
+    ///
+    /// const LEN: usize = text_buffer_len::<DisplaySize128x64, Font6x8>();
+    ///
+    /// let mut display = Ssd1306::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_terminal_mode()
+    /// .with_text_buffer([TextCell::EMPTY; LEN]);
+    /// display.init().unwrap();
+    /// display.clear().unwrap();
+    /// display.set_cursor_style(Some(CursorStyle::Block)).unwrap();
+    ///
+    /// for c in "Name: ".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    ///
+    /// // Call this periodically, e.g. from a timer, to blink the cursor
+    /// display.tick().unwrap();
+    ///
+    /// // Move the cursor over the text and away again
+    /// for column in 0..4 {
+    ///     display.set_position(column, 0).unwrap();
+    ///     display.tick().unwrap();
+    /// }
+    /// display.set_position(6, 0).unwrap();
+    ///
+    /// // The text is still shown, the same as on a display without a cursor
+    /// let mut plain = Ssd1306::new(
+    ///     SimulatedInterface::new(),
+    ///     DisplaySize128x64,
+    ///     DisplayRotation::Rotate0,
+    /// )
+    /// .into_terminal_mode();
+    /// plain.init().unwrap();
+    /// plain.clear().unwrap();
+    /// for c in "Name: ".chars() {
+    ///     plain.print_char(c).unwrap();
+    /// }
+    ///
+    /// let (display, plain) = (display.release(), plain.release());
+    /// for y in 0..8 {
+    ///     for x in 0..36 {
+    ///         assert_eq!(
+    ///             display.pixel::<DisplaySize128x64>(x, y),
+    ///             plain.pixel::<DisplaySize128x64>(x, y),
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub async fn set_cursor_style(
+        &mut self,
+        style: Option<CursorStyle>,
+    ) -> Result<(), TerminalModeError> {
+        if style.is_some() && self.mode.text.as_ref().is_empty() {
+            return Err(TerminalModeError::NoTextBuffer);
+        }
+
+        // Draw the cursor again in the new style
+        self.erase_cursor().await?;
+        self.mode.cursor_style = style;
+        self.mode.cursor_on = true;
+        self.mode.ticks = 0;
+
+        self.refresh_cursor().await
+    }
+
+    /// Set the number of calls to [`tick`](Self::tick) after which a blinking cursor is hidden or
+    /// shown again. The default is 1, and 0 stops the cursor from blinking.
+    pub fn set_cursor_blink(&mut self, ticks: u8) {
+        self.mode.blink_ticks = ticks;
+        self.mode.ticks = 0;
+    }
+
+    /// Hide the cursor if it is shown, or show it if it is hidden by blinking.
+    ///
+    /// This has no effect if the cursor style is `None`, see
+    /// [`set_cursor_style`](Self::set_cursor_style).
+    pub async fn toggle_cursor(&mut self) -> Result<(), TerminalModeError> {
+        self.mode.cursor_on = !self.mode.cursor_on;
+        self.mode.ticks = 0;
+
+        self.refresh_cursor().await
+    }
+
+    /// Blink the cursor by calling this periodically. The cursor is toggled every time the number
+    /// of ticks set with [`set_cursor_blink`](Self::set_cursor_blink) has passed.
+    ///
+    /// Moving the cursor shows it immediately and restarts the count.
+    pub async fn tick(&mut self) -> Result<(), TerminalModeError> {
+        if self.mode.cursor_style.is_none() || self.mode.blink_ticks == 0 {
+            return Ok(());
+        }
+
+        self.mode.ticks += 1;
+        if self.mode.ticks >= self.mode.blink_ticks {
+            self.toggle_cursor().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the attributes used to draw characters.
+    pub fn attributes(&self) -> TextAttributes {
+        self.mode.attributes
+    }
+
+    /// Set the attributes used to draw the following characters.
+    ///
+    /// The attributes can also be changed with escape sequences, see [`TerminalMode`].
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::TextAttributes, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate90)
+    ///     .into_terminal_mode();
+    /// display.init().unwrap();
+    ///
+    /// display.set_attributes(TextAttributes {
+    ///     inverse: true,
+    ///     bold: true,
+    ///     ..TextAttributes::default()
+    /// });
+    /// for c in "> Settings".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    ///
+    /// display.reset_attributes();
+    /// ```
+    pub fn set_attributes(&mut self, attributes: TextAttributes) {
+        self.mode.attributes = attributes;
+    }
+
+    /// Draw the following characters without any attributes.
+    pub fn reset_attributes(&mut self) {
+        self.mode.attributes = TextAttributes::default();
+    }
+
+    /// Draw characters using the glyphs in `glyphs` instead of the glyphs of the font.
+    ///
+    /// This adds characters that are missing from the font, such as symbols, icons or letters
+    /// outside of ASCII, or replaces glyphs of the font. The glyphs are looked up in order, so the
+    /// first glyph for a character is used. Characters without a glyph in the table are drawn
+    /// using the font.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::CustomGlyph, prelude::*, Ssd1306};
+    ///
+    /// const GLYPHS: &[CustomGlyph] = &[
+    ///     // Degree sign
+    ///     CustomGlyph::new('°', &[0x06, 0x09, 0x09, 0x06, 0x00, 0x00]),
+    ///     // Arrow pointing right
+    ///     CustomGlyph::new('→', &[0x08, 0x08, 0x08, 0x3e, 0x1c, 0x08]),
+    /// ];
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    /// display.init().unwrap();
+    /// display.set_custom_glyphs(GLYPHS);
+    ///
+    /// for c in "→ 21°C".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    /// ```
+    pub fn set_custom_glyphs(&mut self, glyphs: &'static [CustomGlyph]) {
+        self.mode.custom_glyphs = glyphs;
+    }
+
     /// Clear the display and reset the cursor to the top left corner
     pub async fn clear(&mut self) -> Result<(), TerminalModeError> {
+        self.blank_display().await?;
+        self.mode.text.as_mut().fill(TextCell::EMPTY);
+        self.reset_pos().await
+    }
+
+    /// Clear the whole display RAM without changing the text buffer or the cursor position.
+    async fn blank_display(&mut self) -> Result<(), TerminalModeError> {
+        self.reset_scroll().await?;
+
         // Let the chip handle line wrapping so we can fill the screen with blanks faster
         self.set_addr_mode(AddrMode::Horizontal).await?;
 
-        let offset_x = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-            }
-        };
+        let offset_x = self.column_offset();
         self.set_draw_area(
             (offset_x, SIZE::OFFSETY),
             (SIZE::WIDTH + offset_x, SIZE::HEIGHT + SIZE::OFFSETY),
@@ -271,46 +1058,108 @@ where
         .await?;
 
         // Clear the display
-        for _ in 0..SIZE::CHAR_NUM {
-            self.draw(&[0; 8]).await?;
-        }
+        self.draw_blank(usize::from(SIZE::WIDTH) * usize::from(SIZE::HEIGHT / 8))
+            .await?;
 
         // But for normal operation we manage the line wrapping
         self.set_addr_mode(AddrMode::Page).await?;
-        self.reset_pos().await
+        self.mode.cursor_drawn = None;
+
+        Ok(())
     }
 
     /// Print a character to the display
+    ///
+    /// Escape sequences are interpreted as described in [`TerminalMode`].
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    /// display.init().unwrap();
+    ///
+    /// // Clear the screen and print an inverted word on the third row
+    /// for c in "\x1b[2J\x1b[3;5H\x1b[7mWARN\x1b[0m".chars() {
+    ///     display.print_char(c).unwrap();
+    /// }
+    ///
+    /// assert_eq!(display.position().unwrap(), (8, 2));
+    /// ```
     pub async fn print_char(&mut self, c: char) -> Result<(), TerminalModeError> {
+        if !matches!(self.mode.escape, EscapeState::Ground) {
+            self.parse_escape(c).await?;
+            return self.refresh_cursor().await;
+        }
+
+        let before = self.position().ok();
+
         match c {
+            '\x1b' => {
+                self.mode.escape = EscapeState::Escape;
+            }
             '\n' => {
                 let CursorWrapEvent(new_line) = self.ensure_cursor()?.advance_line();
+                let new_line = self.scroll_if_wrapped(new_line).await?;
                 self.set_position(0, new_line).await?;
             }
+            '\t' => {
+                let tab_width = self.mode.tab_width;
+                let cursor = self.ensure_cursor()?;
+                if let Some(CursorWrapEvent(new_line)) = cursor.advance_tab(tab_width) {
+                    let new_line = self.scroll_if_wrapped(new_line).await?;
+                    self.set_position(0, new_line).await?;
+                }
+            }
+            // Backspace
+            '\x08' => {
+                let mut cursor = *self.ensure_cursor()?;
+                if cursor.retreat() {
+                    let (column, row) = cursor.get_position();
+                    self.clear_cells(row, column, column + 1).await?;
+                    self.set_position(column, row).await?;
+                }
+            }
+            // Form feed
+            '\x0c' => self.clear().await?,
             '\r' => {
-                self.set_column(0).await?;
                 let (_, cur_line) = self.ensure_cursor()?.get_position();
-                self.ensure_cursor()?.set_position(0, cur_line);
+                self.set_position(0, cur_line).await?;
             }
             _ => {
-                let bitmap = match self.rotation {
-                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                        Self::char_to_bitmap(c)
-                    }
-                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                        let bitmap = Self::char_to_bitmap(c);
-                        Self::rotate_bitmap(bitmap)
-                    }
+                let (column, row) = self.position()?;
+                let cell = TextCell {
+                    c,
+                    attributes: self.mode.attributes,
                 };
 
-                self.draw(&bitmap).await?;
+                // Cells that are drawn already do not need to be sent again, unless the cursor
+                // is drawn over them
+                if self.text_cell(column, row) != Some(cell)
+                    || self.mode.cursor_drawn == Some((column, row))
+                {
+                    let rendered = self.render_char(c, cell.attributes);
+                    self.draw_cell(column, row, &rendered).await?;
+                    self.set_text_cell(column, row, cell);
+                }
+
+                // The character replaces the cursor
+                if self.mode.cursor_drawn == Some((column, row)) {
+                    self.mode.cursor_drawn = None;
+                }
 
                 // Increment character counter and potentially wrap line
                 self.advance_cursor().await?;
             }
         }
 
-        Ok(())
+        if self.position().ok() != before {
+            self.show_moved_cursor();
+        }
+
+        self.refresh_cursor().await
     }
 
     /// Get the current cursor position, in character coordinates.
@@ -331,49 +1180,397 @@ where
         if column >= width || row >= height {
             Err(TerminalModeError::OutOfBounds)
         } else {
-            let offset_x = match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-                DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                    // If segment remapping is flipped, we need to calculate
-                    // the offset from the other edge of the display.
-                    SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-                }
-            };
-            match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                    self.set_column(offset_x + column * 8).await?;
-                    self.set_row(SIZE::OFFSETY + row * 8).await?;
-                }
-                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                    self.set_column(offset_x + row * 8).await?;
-                    self.set_row(SIZE::OFFSETY + column * 8).await?;
-                }
+            let cursor = self.ensure_cursor()?;
+            if cursor.get_position() != (column, row) {
+                cursor.set_position(column, row);
+                self.show_moved_cursor();
             }
-            self.ensure_cursor()?.set_position(column, row);
-            Ok(())
+
+            self.refresh_cursor().await
+        }
+    }
+
+    /// Get the number of (columns, rows) of characters that fit on the display with the current
+    /// font and rotation.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use ssd1306::{mode::Font5x7, prelude::*, Ssd1306};
+    ///
+    /// let display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode_with_font::<Font5x7>();
+    ///
+    /// assert_eq!(display.char_dimensions(), (21, 8));
+    /// ```
+    pub fn char_dimensions(&self) -> (u8, u8) {
+        let (cell_columns, cell_pages) = self.cell_size();
+        let width = SIZE::WIDTH / cell_columns;
+        let height = SIZE::HEIGHT / (cell_pages * 8);
+
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (width, height),
+            // Rows of text run down the display
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (height, width),
         }
     }
 
     /// Reset the draw area and move pointer to the top left corner
     async fn reset_pos(&mut self) -> Result<(), TerminalModeError> {
         // Initialise the counter when we know it's valid
-        let (w, h) = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
-        };
-        self.mode.cursor = Some(Cursor::new(w, h));
+        let (width, height) = self.char_dimensions();
+        self.mode.cursor = Some(Cursor::new(width, height));
+        self.layout_text(width, height);
+
+        // Resetting the start line would move the text on the display
+        if self.mode.top_page != 0 {
+            self.redraw().await?;
+        }
 
         // Reset cursor position
         self.set_position(0, 0).await
     }
 
+    /// Feed a character that is part of an escape sequence to the parser.
+    async fn parse_escape(&mut self, c: char) -> Result<(), TerminalModeError> {
+        self.mode.escape = match (self.mode.escape, c) {
+            (EscapeState::Escape, '[') => EscapeState::Csi {
+                params: [0; MAX_PARAMS],
+                current: 0,
+                ignore: false,
+            },
+            (
+                EscapeState::Csi {
+                    mut params,
+                    current,
+                    ignore,
+                },
+                '0'..='9',
+            ) => {
+                if let Some(param) = params.get_mut(current) {
+                    *param = param
+                        .saturating_mul(10)
+                        .saturating_add(c as u16 - u16::from(b'0'));
+                }
+                EscapeState::Csi {
+                    params,
+                    current,
+                    ignore,
+                }
+            }
+            (
+                EscapeState::Csi {
+                    params,
+                    current,
+                    ignore,
+                },
+                ';',
+            ) => EscapeState::Csi {
+                params,
+                current: current + 1,
+                ignore,
+            },
+            // Private parameters and intermediate bytes
+            (
+                EscapeState::Csi {
+                    params, current, ..
+                },
+                '\x20'..='\x3f',
+            ) => EscapeState::Csi {
+                params,
+                current,
+                ignore: true,
+            },
+            (
+                EscapeState::Csi {
+                    params,
+                    current,
+                    ignore,
+                },
+                '\x40'..='\x7e',
+            ) => {
+                self.mode.escape = EscapeState::Ground;
+                if !ignore {
+                    let count = (current + 1).min(MAX_PARAMS);
+                    self.execute_csi(c, &params[..count]).await?;
+                }
+                EscapeState::Ground
+            }
+            // Unsupported escape sequences and invalid characters end the sequence
+            _ => EscapeState::Ground,
+        };
+
+        Ok(())
+    }
+
+    /// Execute the control sequence with final character `command`.
+    async fn execute_csi(
+        &mut self,
+        command: char,
+        params: &[u16],
+    ) -> Result<(), TerminalModeError> {
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        let (col, row) = self.position()?;
+
+        // Missing parameters and 0 both mean 1 for cursor movements
+        let param = |index: usize| params.get(index).copied().unwrap_or(0);
+        let count = u8::try_from(param(0).max(1)).unwrap_or(u8::MAX);
+
+        match command {
+            'H' | 'f' => {
+                let row = u8::try_from(param(0).max(1) - 1).unwrap_or(u8::MAX);
+                let col = u8::try_from(param(1).max(1) - 1).unwrap_or(u8::MAX);
+                self.set_position(col.min(width - 1), row.min(height - 1))
+                    .await?;
+            }
+            'A' => self.set_position(col, row.saturating_sub(count)).await?,
+            'B' => {
+                self.set_position(col, row.saturating_add(count).min(height - 1))
+                    .await?
+            }
+            'C' => {
+                self.set_position(col.saturating_add(count).min(width - 1), row)
+                    .await?
+            }
+            'D' => self.set_position(col.saturating_sub(count), row).await?,
+            'J' => match param(0) {
+                0 => {
+                    self.clear_cells(row, col, width).await?;
+                    for row in row + 1..height {
+                        self.clear_cells(row, 0, width).await?;
+                    }
+                }
+                1 => {
+                    for row in 0..row {
+                        self.clear_cells(row, 0, width).await?;
+                    }
+                    self.clear_cells(row, 0, col + 1).await?;
+                }
+                2 => {
+                    self.clear().await?;
+                    self.set_position(col, row).await?;
+                }
+                _ => {}
+            },
+            'K' => match param(0) {
+                0 => self.clear_to_end_of_line().await?,
+                1 => self.clear_cells(row, 0, col + 1).await?,
+                2 => self.clear_line(row).await?,
+                _ => {}
+            },
+            'm' => {
+                for attribute in params {
+                    match attribute {
+                        0 => self.mode.attributes = TextAttributes::default(),
+                        1 => self.mode.attributes.bold = true,
+                        4 => self.mode.attributes.underline = true,
+                        7 => self.mode.attributes.inverse = true,
+                        22 => self.mode.attributes.bold = false,
+                        24 => self.mode.attributes.underline = false,
+                        27 => self.mode.attributes.inverse = false,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Clear the characters from column `start` up to but not including column `end` of `row`.
+    async fn clear_cells(&mut self, row: u8, start: u8, end: u8) -> Result<(), TerminalModeError> {
+        if let Some((column, cursor_row)) = self.mode.cursor_drawn {
+            if cursor_row == row && (start..end).contains(&column) {
+                self.mode.cursor_drawn = None;
+            }
+        }
+
+        for column in start..end {
+            self.set_text_cell(column, row, TextCell::EMPTY);
+        }
+
+        match self.rotation {
+            // Characters of a row are next to each other in the same pages
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 if start < end => {
+                let (cell_columns, cell_pages) = self.cell_size();
+                for page in 0..cell_pages {
+                    self.address_cell(start, row, page).await?;
+                    self.draw_blank(usize::from(end - start) * usize::from(cell_columns))
+                        .await?;
+                }
+            }
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {}
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                for column in start..end {
+                    self.draw_cell(column, row, &[0; MAX_CELL_BYTES]).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send `len` blank bytes to the display.
+    async fn draw_blank(&mut self, mut len: usize) -> Result<(), TerminalModeError> {
+        while len > 0 {
+            let chunk = min(len, MAX_CELL_BYTES);
+            self.draw(&[0; MAX_CELL_BYTES][..chunk]).await?;
+            len -= chunk;
+        }
+
+        Ok(())
+    }
+
+    /// Point the display RAM address at page `page` of the character cell at `column`, `row`.
+    async fn address_cell(&mut self, column: u8, row: u8, page: u8) -> Result<(), DisplayError> {
+        let (cell_columns, cell_pages) = self.cell_size();
+        let (x, page) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
+                column * cell_columns,
+                (self.mode.top_page + row * cell_pages + page) % RAM_PAGES,
+            ),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (row * cell_columns, column * cell_pages + page)
+            }
+        };
+
+        let offset_x = self.column_offset();
+        self.set_column(offset_x + x).await?;
+        self.set_row(SIZE::OFFSETY + page * 8).await
+    }
+
+    /// Draw a character cell rendered by [`render_char`](Self::render_char).
+    async fn draw_cell(
+        &mut self,
+        column: u8,
+        row: u8,
+        cell: &[u8; MAX_CELL_BYTES],
+    ) -> Result<(), TerminalModeError> {
+        let (cell_columns, cell_pages) = self.cell_size();
+        let pages = cell.chunks(cell_columns.into()).take(cell_pages.into());
+
+        for (page, bytes) in (0..).zip(pages) {
+            self.address_cell(column, row, page).await?;
+            self.draw(bytes).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Show the display RAM from the first page again after the text has been scrolled
+    async fn reset_scroll(&mut self) -> Result<(), TerminalModeError> {
+        if self.mode.top_page != 0 {
+            self.mode.top_page = 0;
+            Command::StartLine(0).send(&mut self.interface).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Scroll the text up by one row if the cursor wrapped around to the top row and scrolling is
+    /// enabled. Returns the row the cursor should move to.
+    async fn scroll_if_wrapped(&mut self, new_row: u8) -> Result<u8, TerminalModeError> {
+        let landscape = matches!(
+            self.rotation,
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180
+        );
+        if new_row != 0 || !self.mode.scroll || !landscape {
+            return Ok(new_row);
+        }
+
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        let last_row = height - 1;
+        let (_, cell_pages) = self.cell_size();
+
+        // The cursor would move up with the text
+        self.erase_cursor().await?;
+
+        // Move the stored text up by one row as well
+        let (columns, rows) = (usize::from(width), usize::from(height));
+        let text = self.mode.text.as_mut();
+        let end = min(text.len(), columns * rows);
+        if end > columns {
+            text.copy_within(columns..end, 0);
+        }
+        text[end.saturating_sub(columns)..end].fill(TextCell::EMPTY);
+
+        // The old top row becomes the new bottom row
+        self.mode.top_page = (self.mode.top_page + cell_pages) % RAM_PAGES;
+        Command::StartLine(self.mode.top_page * 8)
+            .send(&mut self.interface)
+            .await?;
+
+        // Clear the new bottom row, and any pages below it that do not fit a whole row
+        let offset_x = self.column_offset();
+        for page in last_row * cell_pages..SIZE::HEIGHT / 8 {
+            let page = (self.mode.top_page + page) % RAM_PAGES;
+            self.set_column(offset_x).await?;
+            self.set_row(SIZE::OFFSETY + page * 8).await?;
+            self.draw_blank(SIZE::WIDTH.into()).await?;
+        }
+
+        Ok(last_row)
+    }
+
+    /// Draw the cursor at its position if it is visible, and remove it from where it was drawn
+    /// before.
+    async fn refresh_cursor(&mut self) -> Result<(), TerminalModeError> {
+        let position = match (self.mode.cursor_style, &self.mode.cursor) {
+            (Some(_), Some(cursor)) if self.mode.cursor_on => Some(cursor.get_position()),
+            _ => None,
+        };
+        // The cursor can only be removed again from cells whose character is stored
+        let position = position.filter(|&(column, row)| self.text_cell(column, row).is_some());
+        if position == self.mode.cursor_drawn {
+            return Ok(());
+        }
+
+        self.erase_cursor().await?;
+
+        if let (Some(style), Some((column, row))) = (self.mode.cursor_style, position) {
+            // Draw the cursor over the character in the cell
+            let TextCell { c, mut attributes } =
+                self.text_cell(column, row).unwrap_or(TextCell::EMPTY);
+            match style {
+                CursorStyle::Underline => attributes.underline = true,
+                CursorStyle::Block => attributes.inverse = !attributes.inverse,
+            }
+            let cell = self.render_char(c, attributes);
+            self.draw_cell(column, row, &cell).await?;
+            self.mode.cursor_drawn = position;
+        }
+
+        Ok(())
+    }
+
+    /// Show a blinking cursor right away after it moved.
+    fn show_moved_cursor(&mut self) {
+        self.mode.cursor_on = true;
+        self.mode.ticks = 0;
+    }
+
+    /// Remove the cursor from the display, if it is drawn.
+    async fn erase_cursor(&mut self) -> Result<(), TerminalModeError> {
+        if let Some((column, row)) = self.mode.cursor_drawn.take() {
+            // The cursor is only drawn in cells whose character is stored
+            let cell = self.text_cell(column, row).unwrap_or(TextCell::EMPTY);
+            let rendered = self.render_char(cell.c, cell.attributes);
+            self.draw_cell(column, row, &rendered).await?;
+        }
+
+        Ok(())
+    }
+
     /// Advance the cursor, automatically wrapping lines and/or screens if necessary
     /// Takes in an already-unwrapped cursor to avoid re-unwrapping
     async fn advance_cursor(&mut self) -> Result<(), TerminalModeError> {
         let cursor = self.ensure_cursor()?;
 
-        cursor.advance();
-        let (c, r) = cursor.get_position();
+        let (c, r) = match cursor.advance() {
+            Some(CursorWrapEvent(new_row)) => (0, self.scroll_if_wrapped(new_row).await?),
+            None => cursor.get_position(),
+        };
         self.set_position(c, r).await
     }
 
@@ -384,217 +1581,163 @@ where
             .ok_or(TerminalModeError::Uninitialized)
     }
 
-    fn char_to_bitmap(input: char) -> [u8; 8] {
-        const CHARS: [[u8; 6]; 95] = [
-            // !
-            [0x00, 0x2f, 0x00, 0x00, 0x00, 0x00],
-            // "
-            [0x03, 0x00, 0x03, 0x00, 0x00, 0x00],
-            // #
-            [0x12, 0x3f, 0x12, 0x12, 0x3f, 0x12],
-            // $
-            [0x2e, 0x2a, 0x7f, 0x2a, 0x3a, 0x00],
-            // %
-            [0x23, 0x13, 0x08, 0x04, 0x32, 0x31],
-            // &
-            [0x10, 0x2a, 0x25, 0x2a, 0x10, 0x20],
-            // '
-            [0x02, 0x01, 0x00, 0x00, 0x00, 0x00],
-            // (
-            [0x1e, 0x21, 0x00, 0x00, 0x00, 0x00],
-            // )
-            [0x21, 0x1e, 0x00, 0x00, 0x00, 0x00],
-            // *
-            [0x08, 0x2a, 0x1c, 0x2a, 0x08, 0x00],
-            // +
-            [0x08, 0x08, 0x3e, 0x08, 0x08, 0x00],
-            // ,
-            [0x80, 0x60, 0x00, 0x00, 0x00, 0x00],
-            // -
-            [0x08, 0x08, 0x08, 0x08, 0x08, 0x00],
-            // .
-            [0x30, 0x30, 0x00, 0x00, 0x00, 0x00],
-            // /
-            [0x20, 0x10, 0x08, 0x04, 0x02, 0x00],
-            // 0
-            [0x1e, 0x31, 0x29, 0x25, 0x23, 0x1e],
-            // 1
-            [0x22, 0x21, 0x3f, 0x20, 0x20, 0x20],
-            // 2
-            [0x32, 0x29, 0x29, 0x29, 0x29, 0x26],
-            // 3
-            [0x12, 0x21, 0x21, 0x25, 0x25, 0x1a],
-            // 4
-            [0x18, 0x14, 0x12, 0x3f, 0x10, 0x00],
-            // 5
-            [0x17, 0x25, 0x25, 0x25, 0x25, 0x19],
-            // 6
-            [0x1e, 0x25, 0x25, 0x25, 0x25, 0x18],
-            // 7
-            [0x01, 0x01, 0x31, 0x09, 0x05, 0x03],
-            // 8
-            [0x1a, 0x25, 0x25, 0x25, 0x25, 0x1a],
-            // 9
-            [0x06, 0x29, 0x29, 0x29, 0x29, 0x1e],
-            // :
-            [0x24, 0x00, 0x00, 0x00, 0x00, 0x00],
-            // ;
-            [0x80, 0x64, 0x00, 0x00, 0x00, 0x00],
-            // <
-            [0x08, 0x14, 0x22, 0x00, 0x00, 0x00],
-            // =
-            [0x14, 0x14, 0x14, 0x14, 0x14, 0x00],
-            // >
-            [0x22, 0x14, 0x08, 0x00, 0x00, 0x00],
-            // ?
-            [0x02, 0x01, 0x01, 0x29, 0x05, 0x02],
-            // @
-            [0x1e, 0x21, 0x2d, 0x2b, 0x2d, 0x0e],
-            // A
-            [0x3e, 0x09, 0x09, 0x09, 0x09, 0x3e],
-            // B
-            [0x3f, 0x25, 0x25, 0x25, 0x25, 0x1a],
-            // C
-            [0x1e, 0x21, 0x21, 0x21, 0x21, 0x12],
-            // D
-            [0x3f, 0x21, 0x21, 0x21, 0x12, 0x0c],
-            // E
-            [0x3f, 0x25, 0x25, 0x25, 0x25, 0x21],
-            // F
-            [0x3f, 0x05, 0x05, 0x05, 0x05, 0x01],
-            // G
-            [0x1e, 0x21, 0x21, 0x21, 0x29, 0x1a],
-            // H
-            [0x3f, 0x04, 0x04, 0x04, 0x04, 0x3f],
-            // I
-            [0x21, 0x21, 0x3f, 0x21, 0x21, 0x00],
-            // J
-            [0x10, 0x20, 0x20, 0x20, 0x20, 0x1f],
-            // K
-            [0x3f, 0x04, 0x0c, 0x0a, 0x11, 0x20],
-            // L
-            [0x3f, 0x20, 0x20, 0x20, 0x20, 0x20],
-            // M
-            [0x3f, 0x02, 0x04, 0x04, 0x02, 0x3f],
-            // N
-            [0x3f, 0x02, 0x04, 0x08, 0x10, 0x3f],
-            // O
-            [0x1e, 0x21, 0x21, 0x21, 0x21, 0x1e],
-            // P
-            [0x3f, 0x09, 0x09, 0x09, 0x09, 0x06],
-            // Q
-            [0x1e, 0x21, 0x29, 0x31, 0x21, 0x5e],
-            // R
-            [0x3f, 0x09, 0x09, 0x09, 0x19, 0x26],
-            // S
-            [0x12, 0x25, 0x25, 0x25, 0x25, 0x18],
-            // T
-            [0x01, 0x01, 0x01, 0x3f, 0x01, 0x01],
-            // U
-            [0x1f, 0x20, 0x20, 0x20, 0x20, 0x1f],
-            // V
-            [0x0f, 0x10, 0x20, 0x20, 0x10, 0x0f],
-            // W
-            [0x1f, 0x20, 0x10, 0x10, 0x20, 0x1f],
-            // X
-            [0x21, 0x12, 0x0c, 0x0c, 0x12, 0x21],
-            // Y
-            [0x01, 0x02, 0x3c, 0x02, 0x01, 0x00],
-            // Z
-            [0x21, 0x31, 0x29, 0x25, 0x23, 0x21],
-            // [
-            [0x3f, 0x21, 0x00, 0x00, 0x00, 0x00],
-            // \
-            [0x02, 0x04, 0x08, 0x10, 0x20, 0x00],
-            // ]
-            [0x21, 0x3f, 0x00, 0x00, 0x00, 0x00],
-            // ^
-            [0x04, 0x02, 0x3f, 0x02, 0x04, 0x00],
-            // _
-            [0x40, 0x40, 0x40, 0x40, 0x40, 0x40],
-            // `
-            [0x01, 0x02, 0x00, 0x00, 0x00, 0x00],
-            // a
-            [0x10, 0x2a, 0x2a, 0x2a, 0x3c, 0x00],
-            // b
-            [0x3f, 0x24, 0x24, 0x24, 0x18, 0x00],
-            // c
-            [0x1c, 0x22, 0x22, 0x22, 0x00, 0x00],
-            // d
-            [0x18, 0x24, 0x24, 0x24, 0x3f, 0x00],
-            // e
-            [0x1c, 0x2a, 0x2a, 0x2a, 0x24, 0x00],
-            // f
-            [0x00, 0x3e, 0x05, 0x01, 0x00, 0x00],
-            // g
-            [0x18, 0xa4, 0xa4, 0xa4, 0x7c, 0x00],
-            // h
-            [0x3f, 0x04, 0x04, 0x04, 0x38, 0x00],
-            // i
-            [0x00, 0x24, 0x3d, 0x20, 0x00, 0x00],
-            // j
-            [0x20, 0x40, 0x40, 0x3d, 0x00, 0x00],
-            // k
-            [0x3f, 0x0c, 0x12, 0x20, 0x00, 0x00],
-            // l
-            [0x1f, 0x20, 0x20, 0x00, 0x00, 0x00],
-            // m
-            [0x3e, 0x02, 0x3c, 0x02, 0x3c, 0x00],
-            // n
-            [0x3e, 0x02, 0x02, 0x02, 0x3c, 0x00],
-            // o
-            [0x1c, 0x22, 0x22, 0x22, 0x1c, 0x00],
-            // p
-            [0xfc, 0x24, 0x24, 0x24, 0x18, 0x00],
-            // q
-            [0x18, 0x24, 0x24, 0x24, 0xfc, 0x00],
-            // r
-            [0x3e, 0x04, 0x02, 0x02, 0x00, 0x00],
-            // s
-            [0x24, 0x2a, 0x2a, 0x2a, 0x10, 0x00],
-            // t
-            [0x02, 0x1f, 0x22, 0x20, 0x00, 0x00],
-            // u
-            [0x1e, 0x20, 0x20, 0x20, 0x1e, 0x00],
-            // v
-            [0x06, 0x18, 0x20, 0x18, 0x06, 0x00],
-            // w
-            [0x1e, 0x30, 0x1c, 0x30, 0x1e, 0x00],
-            // x
-            [0x22, 0x14, 0x08, 0x14, 0x22, 0x00],
-            // y
-            [0x1c, 0xa0, 0xa0, 0xa0, 0x7c, 0x00],
-            // z
-            [0x22, 0x32, 0x2a, 0x26, 0x22, 0x00],
-            // {
-            [0x0c, 0x3f, 0x21, 0x00, 0x00, 0x00],
-            // |
-            [0x3f, 0x00, 0x00, 0x00, 0x00, 0x00],
-            // }
-            [0x21, 0x3f, 0x0c, 0x00, 0x00, 0x00],
-            // ~
-            [0x02, 0x01, 0x02, 0x01, 0x00, 0x00],
-            // blank
-            [0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
-        ];
+    /// The index of the character at `column`, `row` in the text buffer, if it is stored.
+    fn text_index(&self, column: u8, row: u8) -> Option<usize> {
+        let (columns, rows) = self.mode.text_dimensions;
+        if column >= columns || row >= rows {
+            return None;
+        }
+
+        let index = usize::from(row) * usize::from(columns) + usize::from(column);
+        (index < self.mode.text.as_ref().len()).then_some(index)
+    }
+
+    /// Get the stored character at `column`, `row`.
+    fn text_cell(&self, column: u8, row: u8) -> Option<TextCell> {
+        let index = self.text_index(column, row)?;
+        Some(self.mode.text.as_ref()[index])
+    }
+
+    /// Store the character at `column`, `row`, if it fits into the text buffer.
+    fn set_text_cell(&mut self, column: u8, row: u8, cell: TextCell) {
+        if let Some(index) = self.text_index(column, row) {
+            self.mode.text.as_mut()[index] = cell;
+        }
+    }
+
+    /// Lay the text buffer out for `width` columns and `height` rows, keeping the characters at
+    /// positions that exist in both layouts.
+    fn layout_text(&mut self, width: u8, height: u8) {
+        let (old_width, old_height) = self.mode.text_dimensions;
+        if (old_width, old_height) == (width, height) {
+            return;
+        }
+        self.mode.text_dimensions = (width, height);
+
+        let text = self.mode.text.as_mut();
+        let mut move_cell = |column: u8, row: u8| {
+            let from = usize::from(row) * usize::from(old_width) + usize::from(column);
+            let cell = match text.get(from) {
+                Some(cell) if column < old_width && row < old_height => *cell,
+                _ => TextCell::EMPTY,
+            };
+            let to = usize::from(row) * usize::from(width) + usize::from(column);
+            if let Some(dst) = text.get_mut(to) {
+                *dst = cell;
+            }
+        };
+
+        // Cells move towards the start of the buffer if rows get shorter, and towards the end if
+        // they get longer, so walk the buffer in that direction to read each cell before it is
+        // overwritten
+        if width <= old_width {
+            for row in 0..height {
+                for column in 0..width {
+                    move_cell(column, row);
+                }
+            }
+        } else {
+            for row in (0..height).rev() {
+                for column in (0..width).rev() {
+                    move_cell(column, row);
+                }
+            }
+        }
+    }
+
+    /// The width of a character cell in pixels.
+    fn cell_width(&self) -> u8 {
+        let width = self.mode.cell_width.unwrap_or(FONT::CELL_WIDTH);
+        min(width, MAX_CELL_WIDTH)
+    }
+
+    /// The size of a character cell on the display in the current rotation, as the number of
+    /// display columns and pages it covers.
+    fn cell_size(&self) -> (u8, u8) {
+        let width = self.cell_width();
+        let pages = min(FONT::PAGES, MAX_CELL_PAGES);
+
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (width, pages),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (pages * 8, width.div_ceil(8))
+            }
+        }
+    }
+
+    /// Render a character into the pages of its cell, in the layout used by the display RAM for
+    /// the current rotation.
+    fn render_char(&self, c: char, attributes: TextAttributes) -> [u8; MAX_CELL_BYTES] {
+        let width = usize::from(self.cell_width());
+        let pages = usize::from(min(FONT::PAGES, MAX_CELL_PAGES));
+
+        // The cell as drawn with `DisplayRotation::Rotate0`, one page after the other
+        let mut cell = [0; MAX_CELL_BYTES];
+        let glyph = match self.mode.custom_glyphs.iter().find(|glyph| glyph.c == c) {
+            Some(custom) => Some(custom.glyph),
+            None => FONT::glyph(c),
+        };
 
-        let g = (input as usize)
-            .checked_sub(b'!'.into())
-            .and_then(|idx| CHARS.get(idx))
-            .unwrap_or(&CHARS[CHARS.len() - 1]);
+        if let Some(glyph) = glyph {
+            let left = usize::from(self.cell_width().saturating_sub(FONT::WIDTH) / 2);
+            let glyph_pages = glyph.chunks(FONT::WIDTH.into()).take(pages);
 
-        [0, g[0], g[1], g[2], g[3], g[4], g[5], 0]
+            for (cell_page, glyph_page) in cell.chunks_mut(width).zip(glyph_pages) {
+                for (dst, src) in cell_page.iter_mut().skip(left).zip(glyph_page) {
+                    *dst = *src;
+                }
+            }
+        }
+
+        let TextAttributes {
+            inverse,
+            underline,
+            bold,
+        } = attributes;
+
+        if bold {
+            for cell_page in cell.chunks_mut(width).take(pages) {
+                for x in (1..width).rev() {
+                    cell_page[x] |= cell_page[x - 1];
+                }
+            }
+        }
+
+        if underline {
+            // The most significant bit of the bottom page is the bottom row
+            for column in &mut cell[(pages - 1) * width..pages * width] {
+                *column |= 0x80;
+            }
+        }
+
+        if inverse {
+            for column in &mut cell[..width * pages] {
+                *column = !*column;
+            }
+        }
+
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => cell,
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                Self::rotate_cell(&cell, width, pages * 8)
+            }
+        }
     }
 
-    fn rotate_bitmap(bitmap: [u8; 8]) -> [u8; 8] {
-        let mut rotated: [u8; 8] = [0; 8];
+    /// Transpose a cell of `width` by `height` pixels, so each column of the result holds a row of
+    /// the cell.
+    fn rotate_cell(
+        cell: &[u8; MAX_CELL_BYTES],
+        width: usize,
+        height: usize,
+    ) -> [u8; MAX_CELL_BYTES] {
+        let mut rotated = [0; MAX_CELL_BYTES];
 
-        for (col, source) in bitmap.iter().enumerate() {
-            // source.msb is the top pixel
-            for (row, item) in rotated.iter_mut().enumerate() {
-                let bit = source & (1 << row) != 0;
-                if bit {
-                    *item |= 1 << col;
+        for x in 0..width {
+            for y in 0..height {
+                // The least significant bit is the top pixel
+                if cell[y / 8 * width + x] & (1 << (y % 8)) != 0 {
+                    rotated[x / 8 * height + y] |= 1 << (x % 8);
                 }
             }
         }
@@ -604,10 +1747,12 @@ where
 }
 
 #[cfg(feature = "async")]
-impl<DI, SIZE> Ssd1306Async<DI, SIZE, TerminalModeAsync>
+impl<DI, SIZE, FONT, BUF> Ssd1306Async<DI, SIZE, TerminalModeAsync<FONT, BUF>>
 where
     DI: AsyncWriteOnlyDataCommand,
     SIZE: TerminalDisplaySizeAsync,
+    FONT: TerminalFont,
+    BUF: AsMut<[TextCell]> + AsRef<[TextCell]>,
 {
     /// Write a string slice to the display
     pub async fn write_str(&mut self, s: &str) -> Result<(), TerminalModeError> {
@@ -616,15 +1761,357 @@ where
         }
         Ok(())
     }
+
+    /// Write formatted text to the display. This makes the `write!` macro work with the async
+    /// terminal mode:
+    ///
+    /// ```rust,ignore
+    /// write!(display, "T: {:.1} C\n", temperature).await?;
+    /// ```
+    ///
+    /// The text is formatted into a buffer of 128 bytes on the stack before it is printed. If a
+    /// formatting trait implementation returns an error, or the text does not fit into the buffer,
+    /// the text formatted up to that point is printed and [`TerminalModeError::FormatError`] is
+    /// returned. Use [`write_str`](Self::write_str) for longer text.
+    ///
+    /// ```rust
+    /// use embassy_futures::block_on;
+    /// use ssd1306::{
+    ///     mode::TerminalModeError,
+    ///     prelude::*,
+    ///     test_helpers::StubInterface,
+    ///     Ssd1306Async,
+    /// };
+    ///
+    /// let mut display = Ssd1306Async::new(StubInterface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    ///
+    /// block_on(async {
+    ///     display.init().await.unwrap();
+    ///     display.clear().await.unwrap();
+    ///
+    ///     write!(display, "{}:{:02}", 12, 5).await.unwrap();
+    ///     assert_eq!(display.position().unwrap(), (5, 0));
+    ///
+    ///     let long = [0u8; 64];
+    ///     assert!(matches!(
+    ///         write!(display, "{:?}", long).await,
+    ///         Err(TerminalModeError::FormatError)
+    ///     ));
+    /// });
+    /// ```
+    pub async fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), TerminalModeError> {
+        if let Some(s) = args.as_str() {
+            return self.write_str(s).await;
+        }
+
+        let mut text = FormatBuffer::new();
+        let result = fmt::write(&mut text, args);
+
+        self.write_str(text.as_str()).await?;
+        result.map_err(|_| TerminalModeError::FormatError)
+    }
 }
 
-impl<DI, SIZE> fmt::Write for Ssd1306<DI, SIZE, TerminalMode>
+/// Collects formatted text on the stack, up to [`FORMAT_BUFFER`] bytes.
+#[cfg(feature = "async")]
+struct FormatBuffer {
+    bytes: [u8; FORMAT_BUFFER],
+    len: usize,
+}
+
+#[cfg(feature = "async")]
+impl FormatBuffer {
+    fn new() -> Self {
+        Self {
+            bytes: [0; FORMAT_BUFFER],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only whole characters are added, so the bytes are always valid UTF-8
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "async")]
+impl fmt::Write for FormatBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let free = FORMAT_BUFFER - self.len;
+
+        // Keep as many whole characters as fit
+        let mut end = min(s.len(), free);
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        self.bytes[self.len..self.len + end].copy_from_slice(&s.as_bytes()[..end]);
+        self.len += end;
+
+        if end < s.len() {
+            Err(fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<DI, SIZE, FONT, BUF> fmt::Write for Ssd1306<DI, SIZE, TerminalMode<FONT, BUF>>
 where
     DI: WriteOnlyDataCommand,
     SIZE: TerminalDisplaySize,
+    FONT: TerminalFont,
+    BUF: AsMut<[TextCell]> + AsRef<[TextCell]>,
 {
+    /// Write a string slice to the display.
+    ///
+    /// If printing a character fails, [`fmt::Error`] is returned and the error is kept until it is
+    /// retrieved with [`take_write_error`](Ssd1306::take_write_error).
     fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
-        s.chars().map(move |c| self.print_char(c)).next_back();
+        for c in s.chars() {
+            if let Err(error) = self.print_char(c) {
+                self.mode.write_error = Some(error);
+                return Err(fmt::Error);
+            }
+        }
+
         Ok(())
     }
 }
+
+impl<DI, SIZE, FONT, BUF> Ssd1306<DI, SIZE, TerminalMode<FONT, BUF>>
+where
+    DI: WriteOnlyDataCommand,
+    SIZE: TerminalDisplaySize,
+    FONT: TerminalFont,
+    BUF: AsMut<[TextCell]> + AsRef<[TextCell]>,
+{
+    /// Take the error that made the last write through [`fmt::Write`] fail, e.g. with the
+    /// `write!` macro.
+    ///
+    /// ```rust
+    /// # use ssd1306::test_helpers::StubInterface;
+    /// # let interface = StubInterface;
+    /// use core::fmt::Write;
+    /// use ssd1306::{mode::TerminalModeError, prelude::*, Ssd1306};
+    ///
+    /// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_terminal_mode();
+    ///
+    /// // The display has not been initialized yet
+    /// assert!(write!(display, "{}", 42).is_err());
+    /// assert!(matches!(
+    ///     display.take_write_error(),
+    ///     Some(TerminalModeError::Uninitialized)
+    /// ));
+    /// ```
+    pub fn take_write_error(&mut self) -> Option<TerminalModeError> {
+        self.mode.write_error.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::SimulatedInterface;
+
+    const ROTATIONS: [DisplayRotation; 2] = [DisplayRotation::Rotate0, DisplayRotation::Rotate90];
+
+    /// Print `text` with `glyphs` and `attributes` on a cleared display and return what the panel
+    /// shows.
+    fn print(
+        rotation: DisplayRotation,
+        glyphs: &'static [CustomGlyph],
+        attributes: TextAttributes,
+        text: &str,
+    ) -> SimulatedInterface {
+        let mut display = Ssd1306::new(SimulatedInterface::new(), DisplaySize128x64, rotation)
+            .into_terminal_mode();
+        display.init().unwrap();
+        display.clear().unwrap();
+        display.set_custom_glyphs(glyphs);
+        display.set_attributes(attributes);
+
+        for c in text.chars() {
+            display.print_char(c).unwrap();
+        }
+
+        display.release()
+    }
+
+    /// Check that the panel shows the 8x8 pixel `cells` from the top left corner of the rotated
+    /// display, each given as its columns with the top pixel in the least significant bit, and
+    /// nothing else.
+    fn assert_shows(panel: &SimulatedInterface, rotation: DisplayRotation, cells: &[[u8; 8]]) {
+        let (width, height) = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (128, 64),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (64, 128),
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                let expected = cells
+                    .get(usize::from(x / 8))
+                    .is_some_and(|cell| y < 8 && cell[usize::from(x % 8)] >> y & 1 != 0);
+                let (panel_x, panel_y) = match rotation {
+                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (x, y),
+                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (127 - y, x),
+                };
+
+                assert_eq!(
+                    panel.pixel::<DisplaySize128x64>(panel_x, panel_y),
+                    expected,
+                    "pixel ({x}, {y}) in {rotation:?}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn custom_glyphs_are_drawn_in_their_cell() {
+        const GLYPHS: &[CustomGlyph] = &[
+            CustomGlyph::new('°', &[0x06, 0x09, 0x09, 0x06, 0x00, 0x00]),
+            CustomGlyph::new('→', &[0x08, 0x08, 0x08, 0x3e, 0x1c, 0x08]),
+        ];
+
+        for rotation in ROTATIONS {
+            let panel = print(rotation, GLYPHS, TextAttributes::default(), "°→");
+
+            assert_shows(
+                &panel,
+                rotation,
+                &[
+                    [0x00, 0x06, 0x09, 0x09, 0x06, 0x00, 0x00, 0x00],
+                    [0x00, 0x08, 0x08, 0x08, 0x3e, 0x1c, 0x08, 0x00],
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn first_custom_glyph_replaces_the_font() {
+        const GLYPHS: &[CustomGlyph] = &[
+            CustomGlyph::new('A', &[0x7f, 0x41, 0x41, 0x41, 0x41, 0x7f]),
+            CustomGlyph::new('A', &[0x01, 0x01, 0x01, 0x01, 0x01, 0x01]),
+        ];
+
+        for rotation in ROTATIONS {
+            let panel = print(rotation, GLYPHS, TextAttributes::default(), "A");
+
+            assert_shows(
+                &panel,
+                rotation,
+                &[[0x00, 0x7f, 0x41, 0x41, 0x41, 0x41, 0x7f, 0x00]],
+            );
+        }
+    }
+
+    #[test]
+    fn characters_without_custom_glyph_fall_back_to_the_font() {
+        const GLYPHS: &[CustomGlyph] =
+            &[CustomGlyph::new('°', &[0x06, 0x09, 0x09, 0x06, 0x00, 0x00])];
+
+        for rotation in ROTATIONS {
+            // 'B' is drawn with the font, '€' is in neither and is left blank
+            let panel = print(rotation, GLYPHS, TextAttributes::default(), "B€°");
+
+            assert_shows(
+                &panel,
+                rotation,
+                &[
+                    [0x00, 0x3f, 0x25, 0x25, 0x25, 0x25, 0x1a, 0x00],
+                    [0x00; 8],
+                    [0x00, 0x06, 0x09, 0x09, 0x06, 0x00, 0x00, 0x00],
+                ],
+            );
+        }
+    }
+
+    #[test]
+    fn attributes_change_the_cell() {
+        // 'A' of the font, centered in its cell
+        let plain = [0x00, 0x3e, 0x09, 0x09, 0x09, 0x09, 0x3e, 0x00];
+        let attributes = |inverse, underline, bold| TextAttributes {
+            inverse,
+            underline,
+            bold,
+        };
+        let cases = [
+            (attributes(false, false, false), plain),
+            (
+                attributes(true, false, false),
+                [0xff, 0xc1, 0xf6, 0xf6, 0xf6, 0xf6, 0xc1, 0xff],
+            ),
+            (
+                attributes(false, true, false),
+                [0x80, 0xbe, 0x89, 0x89, 0x89, 0x89, 0xbe, 0x80],
+            ),
+            (
+                attributes(false, false, true),
+                [0x00, 0x3e, 0x3f, 0x09, 0x09, 0x09, 0x3f, 0x3e],
+            ),
+            // The underline is inverted along with the bold glyph
+            (
+                attributes(true, true, true),
+                [0x7f, 0x41, 0x40, 0x76, 0x76, 0x76, 0x40, 0x41],
+            ),
+        ];
+
+        for rotation in ROTATIONS {
+            for (attributes, cell) in cases {
+                let panel = print(rotation, &[], attributes, "A");
+
+                assert_shows(&panel, rotation, &[cell]);
+            }
+        }
+    }
+
+    #[test]
+    fn attributes_apply_to_custom_glyphs() {
+        const GLYPHS: &[CustomGlyph] =
+            &[CustomGlyph::new('°', &[0x06, 0x09, 0x09, 0x06, 0x00, 0x00])];
+
+        for rotation in ROTATIONS {
+            let attributes = TextAttributes {
+                underline: true,
+                bold: true,
+                ..TextAttributes::default()
+            };
+            let panel = print(rotation, GLYPHS, attributes, "°");
+
+            assert_shows(
+                &panel,
+                rotation,
+                &[[0x80, 0x86, 0x8f, 0x89, 0x8f, 0x86, 0x80, 0x80]],
+            );
+        }
+    }
+
+    #[test]
+    fn reset_attributes_draws_plain_characters() {
+        for rotation in ROTATIONS {
+            let mut display = Ssd1306::new(SimulatedInterface::new(), DisplaySize128x64, rotation)
+                .into_terminal_mode();
+            display.init().unwrap();
+            display.clear().unwrap();
+
+            display.set_attributes(TextAttributes {
+                inverse: true,
+                ..TextAttributes::default()
+            });
+            display.print_char('A').unwrap();
+            display.reset_attributes();
+            display.print_char('A').unwrap();
+
+            assert_shows(
+                &display.release(),
+                rotation,
+                &[
+                    [0xff, 0xc1, 0xf6, 0xf6, 0xf6, 0xf6, 0xc1, 0xff],
+                    [0x00, 0x3e, 0x09, 0x09, 0x09, 0x09, 0x3e, 0x00],
+                ],
+            );
+        }
+    }
+}
@@ -0,0 +1,682 @@
+//! Fonts for [`TerminalMode`](crate::mode::TerminalMode).
+
+/// A font for [`TerminalMode`](crate::mode::TerminalMode).
+///
+/// Glyphs are stored like the display RAM: one byte per column of 8 pixels, with the least
+/// significant bit at the top. Glyphs taller than 8 pixels are split into `PAGES` rows of `WIDTH`
+/// bytes each, stored from top to bottom.
+///
+/// Character cells are at most 16 pixels wide and 4 pages high. When the display is rotated by 90
+/// or 270 degrees, the width of a cell is rounded up to a multiple of 8 pixels.
+pub trait TerminalFont {
+    /// The width of a glyph in pixels
+    const WIDTH: u8;
+
+    /// The height of a glyph in pages of 8 pixels
+    const PAGES: u8;
+
+    /// The width of a character cell in pixels, including the space between characters. Glyphs
+    /// are centered horizontally in the cell.
+    ///
+    /// This can be changed for a display with
+    /// [`set_cell_width`](crate::Ssd1306::set_cell_width).
+    const CELL_WIDTH: u8;
+
+    /// Get the glyph for a character, or `None` to leave the character blank.
+    ///
+    /// The returned slice holds `WIDTH * PAGES` bytes.
+    fn glyph(c: char) -> Option<&'static [u8]>;
+}
+
+/// A glyph for a character that replaces the glyph of the font, see
+/// [`set_custom_glyphs`](crate::Ssd1306::set_custom_glyphs).
+///
+/// The glyph is stored in the same format as the glyphs of the [`TerminalFont`] it is used with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomGlyph {
+    pub(crate) c: char,
+    pub(crate) glyph: &'static [u8],
+}
+
+impl CustomGlyph {
+    /// Create a glyph for the character `c`.
+    pub const fn new(c: char, glyph: &'static [u8]) -> Self {
+        Self { c, glyph }
+    }
+
+    /// Get the character this glyph is drawn for.
+    pub const fn char(&self) -> char {
+        self.c
+    }
+}
+
+/// Look up a glyph in a table of the printable ASCII characters starting at `!`.
+fn ascii_glyph<const N: usize>(table: &'static [[u8; N]], c: char) -> Option<&'static [u8]> {
+    (c as usize)
+        .checked_sub(b'!'.into())
+        .and_then(|index| table.get(index))
+        .map(|glyph| glyph.as_slice())
+}
+
+/// The default font, with 6x8 pixel glyphs in 8 pixel wide cells.
+///
+/// This gives 16 columns and 8 rows of text on a 128x64 display.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Font6x8;
+
+impl TerminalFont for Font6x8 {
+    const WIDTH: u8 = 6;
+    const PAGES: u8 = 1;
+    const CELL_WIDTH: u8 = 8;
+
+    fn glyph(c: char) -> Option<&'static [u8]> {
+        static GLYPHS: [[u8; 6]; 94] = [
+            // !
+            [0x00, 0x2f, 0x00, 0x00, 0x00, 0x00],
+            // "
+            [0x03, 0x00, 0x03, 0x00, 0x00, 0x00],
+            // #
+            [0x12, 0x3f, 0x12, 0x12, 0x3f, 0x12],
+            // $
+            [0x2e, 0x2a, 0x7f, 0x2a, 0x3a, 0x00],
+            // %
+            [0x23, 0x13, 0x08, 0x04, 0x32, 0x31],
+            // &
+            [0x10, 0x2a, 0x25, 0x2a, 0x10, 0x20],
+            // '
+            [0x02, 0x01, 0x00, 0x00, 0x00, 0x00],
+            // (
+            [0x1e, 0x21, 0x00, 0x00, 0x00, 0x00],
+            // )
+            [0x21, 0x1e, 0x00, 0x00, 0x00, 0x00],
+            // *
+            [0x08, 0x2a, 0x1c, 0x2a, 0x08, 0x00],
+            // +
+            [0x08, 0x08, 0x3e, 0x08, 0x08, 0x00],
+            // ,
+            [0x80, 0x60, 0x00, 0x00, 0x00, 0x00],
+            // -
+            [0x08, 0x08, 0x08, 0x08, 0x08, 0x00],
+            // .
+            [0x30, 0x30, 0x00, 0x00, 0x00, 0x00],
+            // /
+            [0x20, 0x10, 0x08, 0x04, 0x02, 0x00],
+            // 0
+            [0x1e, 0x31, 0x29, 0x25, 0x23, 0x1e],
+            // 1
+            [0x22, 0x21, 0x3f, 0x20, 0x20, 0x20],
+            // 2
+            [0x32, 0x29, 0x29, 0x29, 0x29, 0x26],
+            // 3
+            [0x12, 0x21, 0x21, 0x25, 0x25, 0x1a],
+            // 4
+            [0x18, 0x14, 0x12, 0x3f, 0x10, 0x00],
+            // 5
+            [0x17, 0x25, 0x25, 0x25, 0x25, 0x19],
+            // 6
+            [0x1e, 0x25, 0x25, 0x25, 0x25, 0x18],
+            // 7
+            [0x01, 0x01, 0x31, 0x09, 0x05, 0x03],
+            // 8
+            [0x1a, 0x25, 0x25, 0x25, 0x25, 0x1a],
+            // 9
+            [0x06, 0x29, 0x29, 0x29, 0x29, 0x1e],
+            // :
+            [0x24, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // ;
+            [0x80, 0x64, 0x00, 0x00, 0x00, 0x00],
+            // <
+            [0x08, 0x14, 0x22, 0x00, 0x00, 0x00],
+            // =
+            [0x14, 0x14, 0x14, 0x14, 0x14, 0x00],
+            // >
+            [0x22, 0x14, 0x08, 0x00, 0x00, 0x00],
+            // ?
+            [0x02, 0x01, 0x01, 0x29, 0x05, 0x02],
+            // @
+            [0x1e, 0x21, 0x2d, 0x2b, 0x2d, 0x0e],
+            // A
+            [0x3e, 0x09, 0x09, 0x09, 0x09, 0x3e],
+            // B
+            [0x3f, 0x25, 0x25, 0x25, 0x25, 0x1a],
+            // C
+            [0x1e, 0x21, 0x21, 0x21, 0x21, 0x12],
+            // D
+            [0x3f, 0x21, 0x21, 0x21, 0x12, 0x0c],
+            // E
+            [0x3f, 0x25, 0x25, 0x25, 0x25, 0x21],
+            // F
+            [0x3f, 0x05, 0x05, 0x05, 0x05, 0x01],
+            // G
+            [0x1e, 0x21, 0x21, 0x21, 0x29, 0x1a],
+            // H
+            [0x3f, 0x04, 0x04, 0x04, 0x04, 0x3f],
+            // I
+            [0x21, 0x21, 0x3f, 0x21, 0x21, 0x00],
+            // J
+            [0x10, 0x20, 0x20, 0x20, 0x20, 0x1f],
+            // K
+            [0x3f, 0x04, 0x0c, 0x0a, 0x11, 0x20],
+            // L
+            [0x3f, 0x20, 0x20, 0x20, 0x20, 0x20],
+            // M
+            [0x3f, 0x02, 0x04, 0x04, 0x02, 0x3f],
+            // N
+            [0x3f, 0x02, 0x04, 0x08, 0x10, 0x3f],
+            // O
+            [0x1e, 0x21, 0x21, 0x21, 0x21, 0x1e],
+            // P
+            [0x3f, 0x09, 0x09, 0x09, 0x09, 0x06],
+            // Q
+            [0x1e, 0x21, 0x29, 0x31, 0x21, 0x5e],
+            // R
+            [0x3f, 0x09, 0x09, 0x09, 0x19, 0x26],
+            // S
+            [0x12, 0x25, 0x25, 0x25, 0x25, 0x18],
+            // T
+            [0x01, 0x01, 0x01, 0x3f, 0x01, 0x01],
+            // U
+            [0x1f, 0x20, 0x20, 0x20, 0x20, 0x1f],
+            // V
+            [0x0f, 0x10, 0x20, 0x20, 0x10, 0x0f],
+            // W
+            [0x1f, 0x20, 0x10, 0x10, 0x20, 0x1f],
+            // X
+            [0x21, 0x12, 0x0c, 0x0c, 0x12, 0x21],
+            // Y
+            [0x01, 0x02, 0x3c, 0x02, 0x01, 0x00],
+            // Z
+            [0x21, 0x31, 0x29, 0x25, 0x23, 0x21],
+            // [
+            [0x3f, 0x21, 0x00, 0x00, 0x00, 0x00],
+            // \
+            [0x02, 0x04, 0x08, 0x10, 0x20, 0x00],
+            // ]
+            [0x21, 0x3f, 0x00, 0x00, 0x00, 0x00],
+            // ^
+            [0x04, 0x02, 0x3f, 0x02, 0x04, 0x00],
+            // _
+            [0x40, 0x40, 0x40, 0x40, 0x40, 0x40],
+            // `
+            [0x01, 0x02, 0x00, 0x00, 0x00, 0x00],
+            // a
+            [0x10, 0x2a, 0x2a, 0x2a, 0x3c, 0x00],
+            // b
+            [0x3f, 0x24, 0x24, 0x24, 0x18, 0x00],
+            // c
+            [0x1c, 0x22, 0x22, 0x22, 0x00, 0x00],
+            // d
+            [0x18, 0x24, 0x24, 0x24, 0x3f, 0x00],
+            // e
+            [0x1c, 0x2a, 0x2a, 0x2a, 0x24, 0x00],
+            // f
+            [0x00, 0x3e, 0x05, 0x01, 0x00, 0x00],
+            // g
+            [0x18, 0xa4, 0xa4, 0xa4, 0x7c, 0x00],
+            // h
+            [0x3f, 0x04, 0x04, 0x04, 0x38, 0x00],
+            // i
+            [0x00, 0x24, 0x3d, 0x20, 0x00, 0x00],
+            // j
+            [0x20, 0x40, 0x40, 0x3d, 0x00, 0x00],
+            // k
+            [0x3f, 0x0c, 0x12, 0x20, 0x00, 0x00],
+            // l
+            [0x1f, 0x20, 0x20, 0x00, 0x00, 0x00],
+            // m
+            [0x3e, 0x02, 0x3c, 0x02, 0x3c, 0x00],
+            // n
+            [0x3e, 0x02, 0x02, 0x02, 0x3c, 0x00],
+            // o
+            [0x1c, 0x22, 0x22, 0x22, 0x1c, 0x00],
+            // p
+            [0xfc, 0x24, 0x24, 0x24, 0x18, 0x00],
+            // q
+            [0x18, 0x24, 0x24, 0x24, 0xfc, 0x00],
+            // r
+            [0x3e, 0x04, 0x02, 0x02, 0x00, 0x00],
+            // s
+            [0x24, 0x2a, 0x2a, 0x2a, 0x10, 0x00],
+            // t
+            [0x02, 0x1f, 0x22, 0x20, 0x00, 0x00],
+            // u
+            [0x1e, 0x20, 0x20, 0x20, 0x1e, 0x00],
+            // v
+            [0x06, 0x18, 0x20, 0x18, 0x06, 0x00],
+            // w
+            [0x1e, 0x30, 0x1c, 0x30, 0x1e, 0x00],
+            // x
+            [0x22, 0x14, 0x08, 0x14, 0x22, 0x00],
+            // y
+            [0x1c, 0xa0, 0xa0, 0xa0, 0x7c, 0x00],
+            // z
+            [0x22, 0x32, 0x2a, 0x26, 0x22, 0x00],
+            // {
+            [0x0c, 0x3f, 0x21, 0x00, 0x00, 0x00],
+            // |
+            [0x3f, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // }
+            [0x21, 0x3f, 0x0c, 0x00, 0x00, 0x00],
+            // ~
+            [0x02, 0x01, 0x02, 0x01, 0x00, 0x00],
+        ];
+
+        ascii_glyph(&GLYPHS, c)
+    }
+}
+
+/// A compact font with 5x7 pixel glyphs in 6 pixel wide cells.
+///
+/// This gives 21 columns and 8 rows of text on a 128x64 display.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Font5x7;
+
+impl TerminalFont for Font5x7 {
+    const WIDTH: u8 = 5;
+    const PAGES: u8 = 1;
+    const CELL_WIDTH: u8 = 6;
+
+    fn glyph(c: char) -> Option<&'static [u8]> {
+        static GLYPHS: [[u8; 5]; 94] = [
+            // !
+            [0x00, 0x00, 0x5f, 0x00, 0x00],
+            // "
+            [0x00, 0x07, 0x00, 0x07, 0x00],
+            // #
+            [0x14, 0x7f, 0x14, 0x7f, 0x14],
+            // $
+            [0x24, 0x2a, 0x7f, 0x2a, 0x12],
+            // %
+            [0x23, 0x13, 0x08, 0x64, 0x62],
+            // &
+            [0x36, 0x49, 0x55, 0x22, 0x50],
+            // '
+            [0x00, 0x05, 0x03, 0x00, 0x00],
+            // (
+            [0x00, 0x1c, 0x22, 0x41, 0x00],
+            // )
+            [0x00, 0x41, 0x22, 0x1c, 0x00],
+            // *
+            [0x14, 0x08, 0x3e, 0x08, 0x14],
+            // +
+            [0x08, 0x08, 0x3e, 0x08, 0x08],
+            // ,
+            [0x00, 0x50, 0x30, 0x00, 0x00],
+            // -
+            [0x08, 0x08, 0x08, 0x08, 0x08],
+            // .
+            [0x00, 0x60, 0x60, 0x00, 0x00],
+            // /
+            [0x20, 0x10, 0x08, 0x04, 0x02],
+            // 0
+            [0x3e, 0x51, 0x49, 0x45, 0x3e],
+            // 1
+            [0x00, 0x42, 0x7f, 0x40, 0x00],
+            // 2
+            [0x42, 0x61, 0x51, 0x49, 0x46],
+            // 3
+            [0x21, 0x41, 0x45, 0x4b, 0x31],
+            // 4
+            [0x18, 0x14, 0x12, 0x7f, 0x10],
+            // 5
+            [0x27, 0x45, 0x45, 0x45, 0x39],
+            // 6
+            [0x3c, 0x4a, 0x49, 0x49, 0x30],
+            // 7
+            [0x01, 0x71, 0x09, 0x05, 0x03],
+            // 8
+            [0x36, 0x49, 0x49, 0x49, 0x36],
+            // 9
+            [0x06, 0x49, 0x49, 0x29, 0x1e],
+            // :
+            [0x00, 0x36, 0x36, 0x00, 0x00],
+            // ;
+            [0x00, 0x56, 0x36, 0x00, 0x00],
+            // <
+            [0x08, 0x14, 0x22, 0x41, 0x00],
+            // =
+            [0x14, 0x14, 0x14, 0x14, 0x14],
+            // >
+            [0x00, 0x41, 0x22, 0x14, 0x08],
+            // ?
+            [0x02, 0x01, 0x51, 0x09, 0x06],
+            // @
+            [0x32, 0x49, 0x79, 0x41, 0x3e],
+            // A
+            [0x7e, 0x11, 0x11, 0x11, 0x7e],
+            // B
+            [0x7f, 0x49, 0x49, 0x49, 0x36],
+            // C
+            [0x3e, 0x41, 0x41, 0x41, 0x22],
+            // D
+            [0x7f, 0x41, 0x41, 0x22, 0x1c],
+            // E
+            [0x7f, 0x49, 0x49, 0x49, 0x41],
+            // F
+            [0x7f, 0x09, 0x09, 0x09, 0x01],
+            // G
+            [0x3e, 0x41, 0x49, 0x49, 0x7a],
+            // H
+            [0x7f, 0x08, 0x08, 0x08, 0x7f],
+            // I
+            [0x00, 0x41, 0x7f, 0x41, 0x00],
+            // J
+            [0x20, 0x40, 0x41, 0x3f, 0x01],
+            // K
+            [0x7f, 0x08, 0x14, 0x22, 0x41],
+            // L
+            [0x7f, 0x40, 0x40, 0x40, 0x40],
+            // M
+            [0x7f, 0x02, 0x0c, 0x02, 0x7f],
+            // N
+            [0x7f, 0x04, 0x08, 0x10, 0x7f],
+            // O
+            [0x3e, 0x41, 0x41, 0x41, 0x3e],
+            // P
+            [0x7f, 0x09, 0x09, 0x09, 0x06],
+            // Q
+            [0x3e, 0x41, 0x51, 0x21, 0x5e],
+            // R
+            [0x7f, 0x09, 0x19, 0x29, 0x46],
+            // S
+            [0x46, 0x49, 0x49, 0x49, 0x31],
+            // T
+            [0x01, 0x01, 0x7f, 0x01, 0x01],
+            // U
+            [0x3f, 0x40, 0x40, 0x40, 0x3f],
+            // V
+            [0x1f, 0x20, 0x40, 0x20, 0x1f],
+            // W
+            [0x3f, 0x40, 0x38, 0x40, 0x3f],
+            // X
+            [0x63, 0x14, 0x08, 0x14, 0x63],
+            // Y
+            [0x07, 0x08, 0x70, 0x08, 0x07],
+            // Z
+            [0x61, 0x51, 0x49, 0x45, 0x43],
+            // [
+            [0x00, 0x7f, 0x41, 0x41, 0x00],
+            // \
+            [0x02, 0x04, 0x08, 0x10, 0x20],
+            // ]
+            [0x00, 0x41, 0x41, 0x7f, 0x00],
+            // ^
+            [0x04, 0x02, 0x01, 0x02, 0x04],
+            // _
+            [0x40, 0x40, 0x40, 0x40, 0x40],
+            // `
+            [0x00, 0x01, 0x02, 0x04, 0x00],
+            // a
+            [0x20, 0x54, 0x54, 0x54, 0x78],
+            // b
+            [0x7f, 0x48, 0x44, 0x44, 0x38],
+            // c
+            [0x38, 0x44, 0x44, 0x44, 0x20],
+            // d
+            [0x38, 0x44, 0x44, 0x48, 0x7f],
+            // e
+            [0x38, 0x54, 0x54, 0x54, 0x18],
+            // f
+            [0x08, 0x7e, 0x09, 0x01, 0x02],
+            // g
+            [0x0c, 0x52, 0x52, 0x52, 0x3e],
+            // h
+            [0x7f, 0x08, 0x04, 0x04, 0x78],
+            // i
+            [0x00, 0x44, 0x7d, 0x40, 0x00],
+            // j
+            [0x20, 0x40, 0x44, 0x3d, 0x00],
+            // k
+            [0x7f, 0x10, 0x28, 0x44, 0x00],
+            // l
+            [0x00, 0x41, 0x7f, 0x40, 0x00],
+            // m
+            [0x7c, 0x04, 0x18, 0x04, 0x78],
+            // n
+            [0x7c, 0x08, 0x04, 0x04, 0x78],
+            // o
+            [0x38, 0x44, 0x44, 0x44, 0x38],
+            // p
+            [0x7c, 0x14, 0x14, 0x14, 0x08],
+            // q
+            [0x08, 0x14, 0x14, 0x18, 0x7c],
+            // r
+            [0x7c, 0x08, 0x04, 0x04, 0x08],
+            // s
+            [0x48, 0x54, 0x54, 0x54, 0x20],
+            // t
+            [0x04, 0x3f, 0x44, 0x40, 0x20],
+            // u
+            [0x3c, 0x40, 0x40, 0x20, 0x7c],
+            // v
+            [0x1c, 0x20, 0x40, 0x20, 0x1c],
+            // w
+            [0x3c, 0x40, 0x30, 0x40, 0x3c],
+            // x
+            [0x44, 0x28, 0x10, 0x28, 0x44],
+            // y
+            [0x0c, 0x50, 0x50, 0x50, 0x3c],
+            // z
+            [0x44, 0x64, 0x54, 0x4c, 0x44],
+            // {
+            [0x00, 0x08, 0x36, 0x41, 0x00],
+            // |
+            [0x00, 0x00, 0x7f, 0x00, 0x00],
+            // }
+            [0x00, 0x41, 0x36, 0x08, 0x00],
+            // ~
+            [0x10, 0x08, 0x08, 0x10, 0x08],
+        ];
+
+        ascii_glyph(&GLYPHS, c)
+    }
+}
+
+/// A large font with 8x16 pixel glyphs, two pages high.
+///
+/// This gives 16 columns and 4 rows of text on a 128x64 display.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Font8x16;
+
+impl TerminalFont for Font8x16 {
+    const WIDTH: u8 = 8;
+    const PAGES: u8 = 2;
+    const CELL_WIDTH: u8 = 8;
+
+    fn glyph(c: char) -> Option<&'static [u8]> {
+        // Each glyph is the top page followed by the bottom page
+        #[rustfmt::skip]
+        static GLYPHS: [[u8; 16]; 94] = [
+            // !
+            [0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00],
+            // "
+            [0x00, 0x00, 0x38, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // #
+            [0x00, 0x40, 0xf0, 0x40, 0x40, 0xf0, 0x40, 0x00, 0x00, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x00],
+            // $
+            [0x00, 0x60, 0x90, 0xf8, 0x90, 0x10, 0x00, 0x00, 0x00, 0x04, 0x04, 0x0f, 0x04, 0x03, 0x00, 0x00],
+            // %
+            [0x00, 0x10, 0x28, 0x10, 0xc0, 0x20, 0x18, 0x00, 0x00, 0x08, 0x06, 0x01, 0x04, 0x0a, 0x04, 0x00],
+            // &
+            [0x00, 0xc0, 0x20, 0x20, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x06, 0x09, 0x09, 0x0a, 0x04, 0x0a, 0x00],
+            // '
+            [0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // (
+            [0x00, 0x00, 0x00, 0xc0, 0x30, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x06, 0x08, 0x00, 0x00],
+            // )
+            [0x00, 0x00, 0x08, 0x30, 0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x06, 0x01, 0x00, 0x00, 0x00],
+            // *
+            [0x00, 0x20, 0xa8, 0x70, 0x70, 0xa8, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // +
+            [0x00, 0x80, 0x80, 0xe0, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00],
+            // ,
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0c, 0x0c, 0x04, 0x00, 0x00, 0x00],
+            // -
+            [0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // .
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x00, 0x00],
+            // /
+            [0x00, 0x00, 0x00, 0x80, 0x40, 0x20, 0x18, 0x00, 0x0c, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // 0
+            [0x00, 0xe0, 0x10, 0x08, 0x08, 0x10, 0xe0, 0x00, 0x00, 0x03, 0x04, 0x08, 0x08, 0x04, 0x03, 0x00],
+            // 1
+            [0x00, 0x20, 0x10, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00],
+            // 2
+            [0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00, 0x00, 0x0c, 0x0a, 0x09, 0x09, 0x08, 0x08, 0x00],
+            // 3
+            [0x00, 0x08, 0x08, 0x88, 0xc8, 0xa8, 0x18, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // 4
+            [0x00, 0x80, 0x40, 0x20, 0x10, 0xf8, 0x00, 0x00, 0x00, 0x03, 0x02, 0x02, 0x02, 0x0f, 0x02, 0x00],
+            // 5
+            [0x00, 0xf8, 0x88, 0x48, 0x48, 0x48, 0x88, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // 6
+            [0x00, 0xe0, 0x10, 0x88, 0x88, 0x88, 0x00, 0x00, 0x00, 0x07, 0x09, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // 7
+            [0x00, 0x08, 0x08, 0x08, 0xc8, 0x28, 0x18, 0x00, 0x00, 0x00, 0x0c, 0x03, 0x00, 0x00, 0x00, 0x00],
+            // 8
+            [0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // 9
+            [0x00, 0x70, 0x88, 0x88, 0x88, 0x48, 0xf0, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00],
+            // :
+            [0x00, 0x00, 0x40, 0xe0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x00, 0x00],
+            // ;
+            [0x00, 0x00, 0x40, 0xe0, 0x40, 0x00, 0x00, 0x00, 0x00, 0x10, 0x0c, 0x0c, 0x04, 0x00, 0x00, 0x00],
+            // <
+            [0x00, 0x00, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00],
+            // =
+            [0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00],
+            // >
+            [0x00, 0x08, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00],
+            // ?
+            [0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00],
+            // @
+            [0x00, 0xf0, 0x08, 0x88, 0x48, 0x48, 0xf0, 0x00, 0x00, 0x07, 0x08, 0x09, 0x0a, 0x09, 0x03, 0x00],
+            // A
+            [0x00, 0xe0, 0x10, 0x08, 0x08, 0x10, 0xe0, 0x00, 0x00, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x00],
+            // B
+            [0x00, 0xf8, 0x88, 0x88, 0x88, 0x50, 0x20, 0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x05, 0x02, 0x00],
+            // C
+            [0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00],
+            // D
+            [0x00, 0xf8, 0x08, 0x08, 0x08, 0x10, 0xe0, 0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00],
+            // E
+            [0x00, 0xf8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00],
+            // F
+            [0x00, 0xf8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // G
+            [0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00, 0x00, 0x07, 0x08, 0x08, 0x09, 0x05, 0x0f, 0x00],
+            // H
+            [0x00, 0xf8, 0x80, 0x80, 0x80, 0x80, 0xf8, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00],
+            // I
+            [0x00, 0x08, 0x08, 0xf8, 0x08, 0x08, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00],
+            // J
+            [0x00, 0x00, 0x00, 0x08, 0x08, 0xf8, 0x08, 0x08, 0x00, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00, 0x00],
+            // K
+            [0x00, 0xf8, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00],
+            // L
+            [0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00],
+            // M
+            [0xf8, 0x20, 0x40, 0x80, 0x40, 0x20, 0xf8, 0x00, 0x0f, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0f, 0x00],
+            // N
+            [0x00, 0xf8, 0x20, 0x40, 0x80, 0x00, 0xf8, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x01, 0x0f, 0x00],
+            // O
+            [0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0xf0, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // P
+            [0x00, 0xf8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // Q
+            [0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0xf0, 0x00, 0x00, 0x07, 0x08, 0x0a, 0x0c, 0x08, 0x17, 0x00],
+            // R
+            [0x00, 0xf8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00, 0x00, 0x0f, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00],
+            // S
+            [0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x10, 0x00, 0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // T
+            [0x08, 0x08, 0x08, 0xf8, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00],
+            // U
+            [0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // V
+            [0x18, 0xe0, 0x00, 0x00, 0x00, 0xe0, 0x18, 0x00, 0x00, 0x00, 0x07, 0x08, 0x07, 0x00, 0x00, 0x00],
+            // W
+            [0xf8, 0x00, 0x00, 0x80, 0x00, 0x00, 0xf8, 0x00, 0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00],
+            // X
+            [0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00, 0x0c, 0x02, 0x01, 0x00, 0x01, 0x02, 0x0c, 0x00],
+            // Y
+            [0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00],
+            // Z
+            [0x00, 0x08, 0x08, 0x88, 0x48, 0x28, 0x18, 0x00, 0x00, 0x0e, 0x09, 0x08, 0x08, 0x08, 0x08, 0x00],
+            // [
+            [0x00, 0x00, 0xf8, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x00, 0x00],
+            // \
+            [0x18, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x0c, 0x00],
+            // ]
+            [0x00, 0x08, 0x08, 0x08, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x08, 0x0f, 0x00, 0x00, 0x00],
+            // ^
+            [0x00, 0x20, 0x10, 0x08, 0x10, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // _
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00],
+            // `
+            [0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // a
+            [0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00],
+            // b
+            [0x00, 0xf8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0f, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // c
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00],
+            // d
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xf8, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x0f, 0x00],
+            // e
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00],
+            // f
+            [0x00, 0x80, 0xf0, 0x88, 0x88, 0x88, 0x10, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // g
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0x40, 0x00, 0x00, 0x15, 0x2a, 0x2a, 0x2a, 0x29, 0x10, 0x00],
+            // h
+            [0x00, 0xf8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00],
+            // i
+            [0x00, 0x00, 0x40, 0xd0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00],
+            // j
+            [0x00, 0x00, 0x00, 0x00, 0x40, 0xd0, 0x00, 0x00, 0x00, 0x18, 0x20, 0x20, 0x20, 0x1f, 0x00, 0x00],
+            // k
+            [0x00, 0xf8, 0x00, 0x00, 0x80, 0x40, 0x00, 0x00, 0x00, 0x0f, 0x01, 0x01, 0x02, 0x04, 0x08, 0x00],
+            // l
+            [0x00, 0x00, 0x08, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00],
+            // m
+            [0xc0, 0x40, 0x40, 0x80, 0x40, 0x40, 0x80, 0x00, 0x0f, 0x00, 0x00, 0x07, 0x00, 0x00, 0x0f, 0x00],
+            // n
+            [0x00, 0xc0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00],
+            // o
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00],
+            // p
+            [0x00, 0xc0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x3f, 0x02, 0x04, 0x04, 0x04, 0x03, 0x00],
+            // q
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xc0, 0x00, 0x00, 0x03, 0x04, 0x04, 0x04, 0x02, 0x3f, 0x00],
+            // r
+            [0x00, 0x40, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00],
+            // s
+            [0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00, 0x00, 0x04, 0x09, 0x09, 0x0a, 0x0a, 0x04, 0x00],
+            // t
+            [0x00, 0x40, 0xf0, 0x40, 0x40, 0x40, 0x00, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x00],
+            // u
+            [0x00, 0xc0, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00],
+            // v
+            [0x00, 0xc0, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x00, 0x01, 0x06, 0x08, 0x06, 0x01, 0x00, 0x00],
+            // w
+            [0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00],
+            // x
+            [0x00, 0x40, 0x80, 0x00, 0x00, 0x80, 0x40, 0x00, 0x00, 0x08, 0x04, 0x03, 0x03, 0x04, 0x08, 0x00],
+            // y
+            [0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1f, 0x00],
+            // z
+            [0x00, 0x40, 0x40, 0x40, 0x40, 0xc0, 0x40, 0x00, 0x00, 0x08, 0x0c, 0x0a, 0x09, 0x08, 0x08, 0x00],
+            // {
+            [0x00, 0x00, 0x80, 0xb0, 0x48, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x06, 0x09, 0x08, 0x08, 0x00],
+            // |
+            [0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00],
+            // }
+            [0x00, 0x08, 0x08, 0x48, 0xb0, 0x80, 0x00, 0x00, 0x00, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00, 0x00],
+            // ~
+            [0x00, 0x30, 0x08, 0x10, 0x20, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+        ];
+
+        ascii_glyph(&GLYPHS, c)
+    }
+}
@@ -0,0 +1,203 @@
+//! Checks that the byte-wise fast paths of the graphics modes draw the same pixels as drawing
+//! each pixel on its own.
+
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, Point, Size},
+    pixelcolor::BinaryColor,
+    primitives::{PointsIter, Rectangle},
+    Pixel,
+};
+
+use super::{Band, BufferedGraphicsMode, DisplayConfig};
+use crate::{
+    rotation::DisplayRotation, size::DisplaySize128x64, test_helpers::SimulatedInterface, Ssd1306,
+};
+
+type BufferedDisplay =
+    Ssd1306<SimulatedInterface, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>;
+
+const ROTATIONS: [DisplayRotation; 4] = [
+    DisplayRotation::Rotate0,
+    DisplayRotation::Rotate90,
+    DisplayRotation::Rotate180,
+    DisplayRotation::Rotate270,
+];
+
+/// Areas starting and ending within a page, within a single page, aligned to pages, and partly
+/// outside of the display.
+const AREAS: [(Rectangle, BinaryColor); 4] = [
+    (rect(3, 5, 20, 21), BinaryColor::On),
+    (rect(10, 17, 30, 4), BinaryColor::Off),
+    (rect(30, 8, 9, 16), BinaryColor::On),
+    (rect(50, 40, 30, 30), BinaryColor::Off),
+];
+
+const fn rect(x: i32, y: i32, width: u32, height: u32) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
+
+/// A pattern drawn before each test, which must be kept outside of the changed area.
+fn pattern(x: u32, y: u32) -> bool {
+    (x + y) % 3 == 0 || (x * y) % 5 == 1
+}
+
+/// Draw the pattern in buffered graphics mode, then `draw`, and return what the panel shows.
+fn buffered(
+    rotation: DisplayRotation,
+    draw: impl FnOnce(&mut BufferedDisplay),
+) -> SimulatedInterface {
+    let mut display = Ssd1306::new(SimulatedInterface::new(), DisplaySize128x64, rotation)
+        .into_buffered_graphics_mode();
+    display.init().unwrap();
+
+    for y in 0..64 {
+        for x in 0..64 {
+            display.set_pixel(x, y, pattern(x, y));
+        }
+    }
+    draw(&mut display);
+    display.flush().unwrap();
+
+    display.release()
+}
+
+/// Draw the pattern into each band of a banded graphics mode with bands of two pages, then
+/// `draw`, and return what the panel shows.
+fn banded(rotation: DisplayRotation, mut draw: impl FnMut(&mut Band<'_>)) -> SimulatedInterface {
+    let mut display = Ssd1306::new(SimulatedInterface::new(), DisplaySize128x64, rotation)
+        .into_banded_graphics_mode::<256>();
+    display.init().unwrap();
+
+    display
+        .draw_bands(|band| {
+            for y in 0..64 {
+                for x in 0..64 {
+                    band.set_pixel(x, y, pattern(x, y));
+                }
+            }
+            draw(band);
+            Ok(())
+        })
+        .unwrap();
+
+    display.release()
+}
+
+/// Draw `area` pixel by pixel.
+fn draw_per_pixel<D>(target: &mut D, area: &Rectangle, color: BinaryColor)
+where
+    D: DrawTarget<Color = BinaryColor>,
+    D::Error: core::fmt::Debug,
+{
+    target
+        .draw_iter(area.points().map(|point| Pixel(point, color)))
+        .unwrap();
+}
+
+fn assert_same_pixels(actual: &SimulatedInterface, expected: &SimulatedInterface) {
+    for y in 0..64 {
+        for x in 0..128 {
+            assert_eq!(
+                actual.pixel::<DisplaySize128x64>(x, y),
+                expected.pixel::<DisplaySize128x64>(x, y),
+                "pixel ({x}, {y})",
+            );
+        }
+    }
+}
+
+#[test]
+fn fill_solid_matches_per_pixel() {
+    for rotation in ROTATIONS {
+        for (area, color) in AREAS {
+            let filled = buffered(rotation, |display| {
+                display.fill_solid(&area, color).unwrap()
+            });
+            let drawn = buffered(rotation, |display| draw_per_pixel(display, &area, color));
+
+            assert_same_pixels(&filled, &drawn);
+        }
+    }
+}
+
+#[test]
+fn blit_page_image_matches_per_pixel() {
+    // 10x13 pixels, in two pages
+    let (width, height) = (10, 13);
+    let image: [u8; 20] = core::array::from_fn(|i| (i as u8).wrapping_mul(73) ^ 0x5a);
+    let image_pixel = |x: i32, y: i32| image[(y / 8 * width + x) as usize] >> (y % 8) & 1 != 0;
+
+    for rotation in ROTATIONS {
+        // Starting within a page, at a page boundary, and clipped at each edge
+        for (left, top) in [(5, 3), (7, 8), (20, 30), (-3, -5), (120, 58)] {
+            let blitted = buffered(rotation, |display| {
+                display.blit_page_image(left, top, width as u32, height as u32, &image)
+            });
+            let drawn = buffered(rotation, |display| {
+                let pixels = (0..height).flat_map(|y| {
+                    (0..width).map(move |x| {
+                        let color = BinaryColor::from(image_pixel(x, y));
+                        Pixel(Point::new(left + x, top + y), color)
+                    })
+                });
+                display.draw_iter(pixels).unwrap();
+            });
+
+            assert_same_pixels(&blitted, &drawn);
+        }
+    }
+}
+
+#[test]
+fn band_fill_solid_matches_per_pixel() {
+    for rotation in ROTATIONS {
+        for (area, color) in AREAS {
+            let filled = banded(rotation, |band| band.fill_solid(&area, color).unwrap());
+            let drawn = buffered(rotation, |display| draw_per_pixel(display, &area, color));
+
+            assert_same_pixels(&filled, &drawn);
+        }
+    }
+}
+
+#[test]
+fn copy_region_matches_per_pixel() {
+    // Moves up, down, left, right and diagonally, page aligned or not, and partly outside of the
+    // display
+    let copies = [
+        (rect(4, 8, 40, 32), Point::new(4, 0)),
+        (rect(4, 0, 40, 32), Point::new(4, 16)),
+        (rect(4, 3, 40, 29), Point::new(4, 0)),
+        (rect(4, 5, 40, 21), Point::new(4, 12)),
+        (rect(9, 3, 40, 29), Point::new(2, 3)),
+        (rect(2, 3, 40, 29), Point::new(11, 3)),
+        (rect(6, 8, 40, 16), Point::new(1, 8)),
+        (rect(1, 8, 40, 16), Point::new(6, 8)),
+        (rect(5, 7, 30, 30), Point::new(9, 2)),
+        (rect(-4, -6, 30, 30), Point::new(50, 50)),
+    ];
+
+    for rotation in ROTATIONS {
+        for (src, dst) in copies {
+            let copied = buffered(rotation, |display| display.copy_region(&src, dst));
+            let drawn = buffered(rotation, |display| {
+                // Read all pixels before drawing any of them
+                let mut pixels = [[None; 128]; 128];
+                for point in src.intersection(&display.bounding_box()).points() {
+                    let (x, y) = (point.x as u32, point.y as u32);
+                    pixels[y as usize][x as usize] = display.get_pixel(x, y);
+                }
+
+                let moved = src.points().filter_map(|point| {
+                    let (x, y) = (point.x as usize, point.y as usize);
+                    let color = pixels.get(y)?.get(x)?.map(BinaryColor::from)?;
+                    Some(Pixel(point - src.top_left + dst, color))
+                });
+                display.draw_iter(moved).unwrap();
+            });
+
+            assert_same_pixels(&copied, &drawn);
+        }
+    }
+}
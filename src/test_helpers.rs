@@ -1,6 +1,9 @@
 //! Helpers for use in examples and tests
 
-use display_interface::{DisplayError, WriteOnlyDataCommand};
+use crate::size::DisplaySize;
+#[cfg(feature = "async")]
+use display_interface::AsyncWriteOnlyDataCommand;
+use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
 use embedded_hal::{
     digital::{ErrorType, OutputPin},
     i2c,
@@ -76,6 +79,17 @@ impl i2c::I2c for I2cStub {
     }
 }
 
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for I2cStub {
+    async fn transaction(
+        &mut self,
+        _address: u8,
+        _operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
 pub struct PinStub;
@@ -99,13 +113,214 @@ impl OutputPin for PinStub {
 pub struct StubInterface;
 
 impl WriteOnlyDataCommand for StubInterface {
-    fn send_commands(
-        &mut self,
-        _cmd: display_interface::DataFormat<'_>,
-    ) -> Result<(), DisplayError> {
+    fn send_commands(&mut self, _cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        Ok(())
+    }
+    fn send_data(&mut self, _buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncWriteOnlyDataCommand for StubInterface {
+    async fn send_commands(&mut self, _cmd: DataFormat<'_>) -> Result<(), DisplayError> {
         Ok(())
     }
-    fn send_data(&mut self, _buf: display_interface::DataFormat<'_>) -> Result<(), DisplayError> {
+    async fn send_data(&mut self, _buf: DataFormat<'_>) -> Result<(), DisplayError> {
         Ok(())
     }
 }
+
+/// An interface that keeps a copy of the display RAM (GDDRAM) of the controller, to check what
+/// would be shown on the display.
+///
+/// Only the commands affecting where data is written and how the RAM is mapped to the display are
+/// simulated.
+///
+/// A display rotated by 90 degrees shows the same picture as an unrotated one, turned by a quarter
+/// turn:
+///
+/// ```rust
+/// use ssd1306::{prelude::*, test_helpers::SimulatedInterface, Ssd1306};
+///
+/// let mut landscape = Ssd1306::new(
+///     SimulatedInterface::new(),
+///     DisplaySize128x64,
+///     DisplayRotation::Rotate0,
+/// )
+/// .into_buffered_graphics_mode();
+/// let mut portrait = Ssd1306::new(
+///     SimulatedInterface::new(),
+///     DisplaySize128x64,
+///     DisplayRotation::Rotate90,
+/// )
+/// .into_buffered_graphics_mode();
+/// landscape.init().unwrap();
+/// portrait.init().unwrap();
+///
+/// for i in 0..64 {
+///     let (x, y) = (i * 7 % 128, i * 5 % 64);
+///     landscape.set_pixel(x, y, true);
+///     portrait.set_pixel(y, 127 - x, true);
+///
+///     // Also send partial updates
+///     if i % 10 == 0 {
+///         landscape.flush().unwrap();
+///         portrait.flush().unwrap();
+///     }
+/// }
+/// landscape.flush().unwrap();
+/// portrait.flush().unwrap();
+///
+/// let (landscape, portrait) = (landscape.release(), portrait.release());
+/// for y in 0..64 {
+///     for x in 0..128 {
+///         assert_eq!(
+///             landscape.pixel::<DisplaySize128x64>(x, y),
+///             portrait.pixel::<DisplaySize128x64>(x, y),
+///         );
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SimulatedInterface {
+    ram: [[u8; 128]; 8],
+    addr_mode: u8,
+    columns: (u8, u8),
+    pages: (u8, u8),
+    column: u8,
+    page: u8,
+    segment_remap: bool,
+    reverse_com_dir: bool,
+    start_line: u8,
+    written_pages: u8,
+}
+
+impl SimulatedInterface {
+    /// Create an interface with cleared display RAM, in the reset state of the controller.
+    pub fn new() -> Self {
+        Self {
+            ram: [[0; 128]; 8],
+            addr_mode: 0b10,
+            columns: (0, 127),
+            pages: (0, 7),
+            column: 0,
+            page: 0,
+            segment_remap: false,
+            reverse_com_dir: false,
+            start_line: 0,
+            written_pages: 0,
+        }
+    }
+
+    /// Get the pages of display RAM that data was written to since the last call, as a bit mask
+    /// with page 0 in the least significant bit.
+    pub fn take_written_pages(&mut self) -> u8 {
+        core::mem::take(&mut self.written_pages)
+    }
+
+    /// Get the state of the pixel at (x, y) on a display of size `SIZE`, as seen with the display
+    /// in its unrotated orientation.
+    pub fn pixel<SIZE: DisplaySize>(&self, x: u8, y: u8) -> bool {
+        let column = if self.segment_remap {
+            SIZE::OFFSETX + x
+        } else {
+            SIZE::DRIVER_COLS - 1 - SIZE::OFFSETX - x
+        };
+        let com = if self.reverse_com_dir {
+            y
+        } else {
+            SIZE::HEIGHT - 1 - y
+        };
+        let row = (com + SIZE::OFFSETY + self.start_line) % 64;
+
+        self.ram[usize::from(row / 8)][usize::from(column)] & (1 << (row % 8)) != 0
+    }
+
+    fn write(&mut self, byte: u8) {
+        self.ram[usize::from(self.page % 8)][usize::from(self.column % 128)] = byte;
+        self.written_pages |= 1 << (self.page % 8);
+
+        match self.addr_mode {
+            // Horizontal
+            0b00 if self.column < self.columns.1 => self.column += 1,
+            0b00 => {
+                self.column = self.columns.0;
+                self.page = if self.page < self.pages.1 {
+                    self.page + 1
+                } else {
+                    self.pages.0
+                };
+            }
+            // Vertical
+            0b01 if self.page < self.pages.1 => self.page += 1,
+            0b01 => {
+                self.page = self.pages.0;
+                self.column = if self.column < self.columns.1 {
+                    self.column + 1
+                } else {
+                    self.columns.0
+                };
+            }
+            // Page
+            _ => self.column = (self.column + 1) % 128,
+        }
+    }
+}
+
+impl Default for SimulatedInterface {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WriteOnlyDataCommand for SimulatedInterface {
+    fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        let DataFormat::U8(cmd) = cmd else {
+            return Err(DisplayError::DataFormatNotImplemented);
+        };
+
+        match *cmd {
+            [0x20, mode] => self.addr_mode = mode & 0b11,
+            [0x21, start, end] => {
+                self.columns = (start, end);
+                self.column = start;
+            }
+            [0x22, start, end] => {
+                self.pages = (start, end);
+                self.page = start;
+            }
+            [low @ 0x00..=0x0f, high @ 0x10..=0x1f] => self.column = (high & 0x0f) << 4 | low,
+            [low @ 0x00..=0x0f] => self.column = self.column & 0xf0 | low,
+            [high @ 0x10..=0x1f] => self.column = self.column & 0x0f | (high & 0x0f) << 4,
+            [line @ 0x40..=0x7f] => self.start_line = line & 0x3f,
+            [remap @ (0xa0 | 0xa1)] => self.segment_remap = remap == 0xa1,
+            [dir @ (0xc0 | 0xc8)] => self.reverse_com_dir = dir == 0xc8,
+            [page @ 0xb0..=0xb7] => self.page = page & 0x07,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        let DataFormat::U8(buf) = buf else {
+            return Err(DisplayError::DataFormatNotImplemented);
+        };
+
+        buf.iter().for_each(|byte| self.write(*byte));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncWriteOnlyDataCommand for SimulatedInterface {
+    async fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+        WriteOnlyDataCommand::send_commands(self, cmd)
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+        WriteOnlyDataCommand::send_data(self, buf)
+    }
+}